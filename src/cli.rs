@@ -1,16 +1,49 @@
-use crate::Style;
+use crate::{
+    notify::Backend,
+    renderer::BarMode,
+    theme::ThemeFile,
+    timer::{next_deadline, sort_by_deadline},
+    tmux, ColorDepth, Config, Format, Output, Preset, Style, Theme, Threshold, Thresholds, Timer,
+    Timespan,
+};
 use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Timelike};
-use clap::{ArgMatches, Command};
+use clap::{ArgGroup, ArgMatches, Command};
 use regex::Regex;
-use std::process::exit;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::exit,
+};
 
+// One field per command-line flag, as clap reports them
 #[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Args {
     pub start: DateTime<Local>,
     pub end: DateTime<Local>,
     pub interval: u64,
     pub title: Option<String>,
     pub style: Style,
+    pub theme: Theme,
+    pub color_depth: ColorDepth,
+    pub thresholds: Thresholds,
+    pub axis: bool,
+    pub ideal_line: bool,
+    pub holidays: Vec<NaiveDate>,
+    pub dashboard: bool,
+    pub timers: Vec<Timer>,
+    pub once: bool,
+    pub format: Format,
+    pub output: Output,
+    pub inline: bool,
+    pub notify: Option<Backend>,
+    pub milestones: Vec<Threshold>,
+    pub bell: bool,
+    pub sound: Option<PathBuf>,
+    pub cue_milestones: Vec<Threshold>,
+    pub window_title: bool,
+    pub taskbar: bool,
+    pub config: Config,
 }
 
 impl Args {
@@ -18,14 +51,17 @@ impl Args {
     #[allow(clippy::missing_panics_doc)]
     #[allow(clippy::needless_pass_by_value)]
     pub fn parse(matches: ArgMatches) -> Self {
-        let start = matches
-            .get_one::<DateTime<Local>>("start")
-            .copied()
-            .unwrap();
-        let end = matches
-            .get_one::<DateTime<Local>>("end")
+        let config = matches
+            .get_one::<Config>("config")
             .copied()
-            .unwrap_or_else(|| start + matches.get_one::<Duration>("duration").copied().unwrap());
+            .unwrap_or_else(|| {
+                Config::find().unwrap_or_else(|e| {
+                    println!("{e}");
+                    exit(1);
+                })
+            });
+        let timers = timers_from(&matches);
+        let (start, end) = timespan_from(&matches, &config, &timers);
 
         if end < start {
             println!(
@@ -35,20 +71,167 @@ impl Args {
             );
             exit(1);
         }
+        let style = matches.get_one::<Style>("style").copied().unwrap();
+        let theme = theme_from(&matches, style);
+        let color_depth = matches.get_one::<ColorDepth>("color").copied().unwrap();
         Args {
             title: matches.get_one::<String>("title").cloned(),
             start,
             end,
             interval: *matches.get_one::<u64>("interval").unwrap(),
-            style: matches.get_one::<Style>("style").copied().unwrap(),
+            style,
+            theme: theme.with_color_depth(color_depth),
+            color_depth,
+            thresholds: Thresholds {
+                warning: *matches.get_one::<Option<Threshold>>("warn").unwrap(),
+                critical: *matches.get_one::<Option<Threshold>>("critical").unwrap(),
+            },
+            axis: matches.get_flag("axis"),
+            ideal_line: matches.get_flag("ideal-line"),
+            holidays: matches
+                .get_many::<NaiveDate>("holiday")
+                .map(|dates| dates.copied().collect())
+                .unwrap_or_default(),
+            dashboard: matches.get_flag("dashboard"),
+            timers,
+            once: matches.get_flag("once"),
+            format: matches.get_one::<Format>("format").copied().unwrap(),
+            // The dashboard has no single status line, so it always takes the TUI
+            output: if matches.get_flag("dashboard") {
+                Output::Tui
+            } else {
+                matches.get_one::<Output>("output").copied().unwrap()
+            },
+            inline: matches.get_flag("inline"),
+            notify: matches.get_one::<Backend>("notify").copied(),
+            milestones: milestones_from(&matches, "notify-at"),
+            bell: matches.get_flag("bell"),
+            sound: matches.get_one::<PathBuf>("sound").cloned(),
+            cue_milestones: milestones_from(&matches, "cue-at"),
+            window_title: matches.get_flag("window-title"),
+            taskbar: matches.get_flag("taskbar"),
+            config,
         }
     }
 }
 
+fn timers_from(matches: &ArgMatches) -> Vec<Timer> {
+    let mut timers: Vec<Timer> = matches
+        .get_many::<Timer>("timer")
+        .map(|timers| timers.cloned().collect())
+        .unwrap_or_default();
+    if let Some(files) = matches.get_many::<Vec<Timer>>("timer-file") {
+        timers.extend(files.flatten().cloned());
+    }
+    sort_by_deadline(&mut timers);
+    timers
+}
+
+fn timespan_from(
+    matches: &ArgMatches,
+    config: &Config,
+    timers: &[Timer],
+) -> (DateTime<Local>, DateTime<Local>) {
+    if let Some(preset) = matches.get_one::<Preset>("preset") {
+        let now = Local::now().naive_local();
+        let timespan = preset.timespan(now, &config.calendar).unwrap_or_else(|e| {
+            println!("{e}");
+            exit(1);
+        });
+        (
+            convert_from_utc(&timespan.from),
+            convert_from_utc(&timespan.to),
+        )
+    } else {
+        let start = matches
+            .get_one::<DateTime<Local>>("start")
+            .copied()
+            .unwrap();
+        let end = matches
+            .get_one::<DateTime<Local>>("end")
+            .copied()
+            .or_else(|| {
+                matches
+                    .get_one::<Duration>("duration")
+                    .map(|duration| start + *duration)
+            });
+        // Without an end, timers report their next deadline as `doit tmux`
+        // does, and the dashboard brings its own spans
+        match (end, next_deadline(timers, Local::now().naive_local())) {
+            (Some(end), _) => (start, end),
+            (None, Some(timer)) => (
+                convert_from_utc(&timer.timespan.from),
+                convert_from_utc(&timer.timespan.to),
+            ),
+            (None, None) => (start, start + Duration::days(1)),
+        }
+    }
+}
+
+fn theme_from(matches: &ArgMatches, style: Style) -> Theme {
+    let theme = Theme::for_style(style);
+    let mut theme = matches
+        .get_one::<ThemeFile>("theme")
+        .map_or(theme, |overrides| theme.with_overrides(overrides));
+    if let Some(bar) = matches.get_one::<BarMode>("bar") {
+        theme.glyphs.bar = *bar;
+    }
+    theme
+}
+
+fn milestones_from(matches: &ArgMatches, id: &str) -> Vec<Threshold> {
+    matches
+        .get_many::<Threshold>(id)
+        .map(|milestones| milestones.copied().collect())
+        .unwrap_or_default()
+}
+
 pub fn build_command() -> Command {
-    Command::new("doit")
+    let mut command = Command::new("doit")
         .version(env!("CARGO_PKG_VERSION"))
         .about("Just Do It! - Progress Bar Tool for Motivation")
+        .subcommand_negates_reqs(true)
+        .args_conflicts_with_subcommands(true)
+        .subcommand(
+            Command::new("tmux")
+                .about("Print the running timer for the tmux status line")
+                .arg(
+                    clap::Arg::new("width")
+                        .long("width")
+                        .value_parser(clap::value_parser!(usize))
+                        .default_value(tmux::DEFAULT_WIDTH.to_string())
+                        .help("Cells of the mini bar"),
+                )
+                .arg(
+                    clap::Arg::new("snippet")
+                        .long("snippet")
+                        .action(clap::ArgAction::SetTrue)
+                        .help("Print the tmux commands that add this to status-right"),
+                ),
+        )
+        .subcommand(
+            Command::new("prompt").about("Print the running timer as a short shell prompt segment"),
+        );
+    for add_args in [
+        timespan_args,
+        style_args,
+        mode_args,
+        alert_args,
+        terminal_args,
+    ] {
+        command = add_args(command);
+    }
+    command.arg(
+        clap::Arg::new("config")
+            .long("config")
+            .value_parser(parse_config)
+            .help("Config file path (default: doit/config.toml in the config directory)"),
+    )
+}
+
+// Title, the timespan and how often it refreshes
+fn timespan_args(command: Command) -> Command {
+    command
         .arg(
             clap::Arg::new("title")
                 .short('t')
@@ -66,7 +249,13 @@ pub fn build_command() -> Command {
         )
         .arg(
             clap::Arg::new("end")
-                .required(true)
+                .required_unless_present_any([
+                    "duration",
+                    "preset",
+                    "dashboard",
+                    "timer",
+                    "timer-file",
+                ])
                 .short('e')
                 .long("end")
                 .value_parser(parse_end_time)
@@ -75,13 +264,24 @@ pub fn build_command() -> Command {
         )
         .arg(
             clap::Arg::new("duration")
-                .required(true)
+                .required_unless_present_any(["end", "preset", "dashboard", "timer", "timer-file"])
                 .short('d')
                 .long("duration")
                 .value_parser(parse_duration)
                 .conflicts_with("end")
                 .help("Duration (mutually exclusive with --end)"),
         )
+        .arg(
+            clap::Arg::new("preset")
+                .short('p')
+                .long("preset")
+                .value_parser(parse_preset)
+                .conflicts_with_all(["start", "end", "duration"])
+                .help(format!(
+                    "Timespan around the current time, instead of --start and --end [{}]",
+                    Preset::NAMES.join("|")
+                )),
+        )
         .arg(
             clap::Arg::new("interval")
                 .required(false)
@@ -91,13 +291,195 @@ pub fn build_command() -> Command {
                 .default_value("1")
                 .help("Refresh interval in seconds"),
         )
+}
+
+// How the progress is drawn
+fn style_args(command: Command) -> Command {
+    command
         .arg(
             clap::Arg::new("style")
                 .short('S')
                 .long("style")
                 .value_parser(parse_style)
                 .default_value("default")
-                .help("Display style [default|hourglass|retro|synthwave]"),
+                .help("Display style [default|hourglass|retro|synthwave|bigclock|ring|graph|calendar|vertical|gantt]"),
+        )
+        .arg(
+            clap::Arg::new("theme")
+                .short('T')
+                .long("theme")
+                .value_parser(parse_theme)
+                .help(format!(
+                    "Theme file path or name [{}]",
+                    ThemeFile::builtin_names().join("|")
+                )),
+        )
+        .arg(
+            clap::Arg::new("bar")
+                .long("bar")
+                .value_parser(parse_bar_mode)
+                .help("Bar fill (default: from theme) [smooth|block|ascii]"),
+        )
+        .arg(
+            clap::Arg::new("warn")
+                .long("warn")
+                .value_parser(parse_threshold)
+                .default_value("10m")
+                .help("Remaining time or percent to switch to warning colors, or 'off'"),
+        )
+        .arg(
+            clap::Arg::new("critical")
+                .long("critical")
+                .value_parser(parse_threshold)
+                .default_value("2m")
+                .help("Remaining time or percent to switch to critical colors, or 'off'"),
+        )
+        .arg(
+            clap::Arg::new("color")
+                .long("color")
+                .value_parser(parse_color_depth)
+                .default_value("auto")
+                .help("Color output [auto|always|never|16|256|truecolor]"),
+        )
+        .arg(
+            clap::Arg::new("axis")
+                .long("axis")
+                .action(clap::ArgAction::SetTrue)
+                .help("Show a time axis under the bar (default style)"),
+        )
+        .arg(
+            clap::Arg::new("ideal-line")
+                .long("ideal-line")
+                .action(clap::ArgAction::SetTrue)
+                .help("Draw the ideal line, solid up to now (graph style)"),
+        )
+        .arg(
+            clap::Arg::new("holiday")
+                .long("holiday")
+                .value_parser(parse_date)
+                .action(clap::ArgAction::Append)
+                .help("Date to shade like a weekend, may be repeated (calendar style)"),
+        )
+}
+
+// The dashboard, timers and the outputs other than the TUI
+fn mode_args(command: Command) -> Command {
+    command
+        .arg(
+            clap::Arg::new("dashboard")
+                .long("dashboard")
+                .action(clap::ArgAction::SetTrue)
+                .conflicts_with_all(["once", "output", "inline"])
+                .help("Show today, this week, month, quarter and year at once"),
+        )
+        .arg(
+            clap::Arg::new("timer")
+                .long("timer")
+                .value_parser(parse_timer)
+                .action(clap::ArgAction::Append)
+                .help("Named timer NAME=END, NAME=DURATION or NAME=START..END, may be repeated"),
+        )
+        .arg(
+            clap::Arg::new("timer-file")
+                .long("timer-file")
+                .value_parser(parse_timer_file)
+                .action(clap::ArgAction::Append)
+                .help("File with one --timer value per line"),
+        )
+        .arg(
+            clap::Arg::new("once")
+                .long("once")
+                .action(clap::ArgAction::SetTrue)
+                .help("Print the current progress once and exit, without the TUI"),
+        )
+        .arg(
+            clap::Arg::new("format")
+                .long("format")
+                .value_parser(parse_format)
+                .default_value("text")
+                .requires("once")
+                .help("Output of --once [text|json|waybar|i3bar]"),
+        )
+        .arg(
+            clap::Arg::new("output")
+                .long("output")
+                .value_parser(parse_output)
+                .default_value("auto")
+                .conflicts_with("once")
+                .help("Output on every refresh, auto picks text when stdout is not a terminal [auto|tui|text|ndjson|waybar|i3bar]"),
+        )
+        .arg(
+            clap::Arg::new("inline")
+                .long("inline")
+                .action(clap::ArgAction::SetTrue)
+                .conflicts_with("once")
+                .help("Redraw one compact line at the cursor instead of clearing the screen"),
+        )
+}
+
+// Notifications and cues at completion and milestones
+fn alert_args(command: Command) -> Command {
+    command
+        .arg(
+            clap::Arg::new("notify")
+                .long("notify")
+                .value_parser(parse_notify)
+                .num_args(0..=1)
+                .default_missing_value("auto")
+                .conflicts_with("once")
+                .help("Send a desktop notification on completion [auto|dbus|notify-send]"),
+        )
+        .arg(
+            clap::Arg::new("notify-at")
+                .long("notify-at")
+                .value_parser(parse_milestone)
+                .value_delimiter(',')
+                .action(clap::ArgAction::Append)
+                .requires("notify")
+                .help("Remaining times or percents to also notify at, such as 15m,50%"),
+        )
+        .arg(
+            clap::Arg::new("bell")
+                .long("bell")
+                .action(clap::ArgAction::SetTrue)
+                .conflicts_with("once")
+                .help("Ring the terminal bell on completion"),
+        )
+        .arg(
+            clap::Arg::new("sound")
+                .long("sound")
+                .value_parser(parse_sound)
+                .conflicts_with("once")
+                .help("Sound file to play on completion, such as a .wav"),
+        )
+        .arg(
+            clap::Arg::new("cue-at")
+                .long("cue-at")
+                .value_parser(parse_milestone)
+                .value_delimiter(',')
+                .action(clap::ArgAction::Append)
+                .requires("cue")
+                .help("Remaining times or percents to also ring or play at, such as 5m,1m"),
+        )
+        .group(ArgGroup::new("cue").args(["bell", "sound"]).multiple(true))
+}
+
+// Progress shown in the window title and the taskbar
+fn terminal_args(command: Command) -> Command {
+    command
+        .arg(
+            clap::Arg::new("window-title")
+                .long("window-title")
+                .action(clap::ArgAction::SetTrue)
+                .conflicts_with("once")
+                .help("Show the progress in the terminal window title"),
+        )
+        .arg(
+            clap::Arg::new("taskbar")
+                .long("taskbar")
+                .action(clap::ArgAction::SetTrue)
+                .conflicts_with("once")
+                .help("Show the progress in the tab or taskbar with OSC 9;4 (Windows Terminal, ConEmu)"),
         )
 }
 
@@ -202,13 +584,117 @@ fn parse_style(s: &str) -> Result<Style, String> {
     Ok(Style::from_name(s))
 }
 
-#[cfg(test)]
-mod tests {
-    use std::result;
+fn parse_theme(s: &str) -> Result<ThemeFile, String> {
+    ThemeFile::find(s).map_err(|e| e.to_string())
+}
 
-    use assert_cmd::assert;
+fn parse_config(s: &str) -> Result<Config, String> {
+    Config::load(Path::new(s)).map_err(|e| e.to_string())
+}
 
+fn parse_preset(s: &str) -> Result<Preset, String> {
+    Preset::from_name(s).ok_or_else(|| format!("Invalid preset: {s}"))
+}
+
+// NAME=END, NAME=DURATION or NAME=START..END, starting now unless given
+fn parse_timer(s: &str) -> Result<Timer, String> {
+    let invalid = |message: String| format!("Invalid timer {s}: {message}");
+    let (name, span) = s
+        .split_once('=')
+        .ok_or_else(|| invalid("expected NAME=END".to_string()))?;
+    let (name, span) = (name.trim(), span.trim());
+    if name.is_empty() {
+        return Err(invalid("missing name".to_string()));
+    }
+    let now = Local::now().naive_local().with_nanosecond(0).unwrap();
+    let (from, to) = if let Some((start, end)) = span.split_once("..") {
+        (
+            parse_start_time(start.trim())?.naive_utc(),
+            parse_end_time(end.trim())?.naive_utc(),
+        )
+    } else if let Ok(duration) = parse_duration(span) {
+        (now, now + duration)
+    } else {
+        (now, parse_end_time(span)?.naive_utc())
+    };
+    let timespan = Timespan::new(from, to).map_err(|e| invalid(e.to_string()))?;
+    Ok(Timer::new(name, timespan))
+}
+
+// One timer per line, skipping blank lines and # comments
+fn parse_timer_file(s: &str) -> Result<Vec<Timer>, String> {
+    let source = fs::read_to_string(s).map_err(|e| format!("{s}: {e}"))?;
+    source
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(number, line)| parse_timer(line).map_err(|e| format!("{s}:{number}: {e}")))
+        .collect()
+}
+
+fn parse_format(s: &str) -> Result<Format, String> {
+    Format::from_name(s).ok_or_else(|| format!("Invalid format: {s}"))
+}
+
+fn parse_output(s: &str) -> Result<Output, String> {
+    Output::from_name(s).ok_or_else(|| format!("Invalid output: {s}"))
+}
+
+fn parse_threshold(s: &str) -> Result<Option<Threshold>, String> {
+    if s == "off" {
+        return Ok(None);
+    }
+    if let Some(percent) = s.strip_suffix('%') {
+        return match percent.parse::<f64>() {
+            Ok(percent) if (0.0..=100.0).contains(&percent) => {
+                Ok(Some(Threshold::Percent(percent)))
+            }
+            _ => Err(format!("Invalid threshold: {s}")),
+        };
+    }
+    parse_duration(s)
+        .map(|duration| Some(Threshold::Remaining(duration)))
+        .map_err(|_| format!("Invalid threshold: {s}"))
+}
+
+fn parse_milestone(s: &str) -> Result<Threshold, String> {
+    parse_threshold(s)?.ok_or_else(|| format!("Invalid milestone: {s}"))
+}
+
+fn parse_sound(s: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(s);
+    if !path.is_file() {
+        return Err(format!("Sound file not found: {s}"));
+    }
+    Ok(path)
+}
+
+fn parse_notify(s: &str) -> Result<Backend, String> {
+    Backend::from_name(s).ok_or_else(|| format!("Invalid notify: {s}"))
+}
+
+fn parse_bar_mode(s: &str) -> Result<BarMode, String> {
+    BarMode::from_name(s).ok_or_else(|| format!("Invalid bar: {s}"))
+}
+
+fn parse_color_depth(s: &str) -> Result<ColorDepth, String> {
+    ColorDepth::from_name(s).ok_or_else(|| format!("Invalid color: {s}"))
+}
+
+#[cfg(test)]
+mod tests {
     use super::*;
+    use crossterm::style::Color;
+
+    // Parses against an empty config file, so that the tests never read the
+    // config of whoever runs them
+    fn parse(args: &[&str]) -> Args {
+        let config = tempfile::NamedTempFile::new().unwrap();
+        let mut args = args.to_vec();
+        args.extend(["--config", config.path().to_str().unwrap()]);
+        Args::parse(build_command().get_matches_from(args))
+    }
 
     #[test]
     fn test_parse_with_start() {
@@ -219,8 +705,7 @@ mod tests {
             "--end",
             "2025-01-31 23:59:59",
         ];
-        let command = build_command();
-        let args = Args::parse(command.get_matches_from(args));
+        let args = parse(&args);
         assert_eq!(
             args.start.format("%Y-%m-%d %H:%M:%S").to_string(),
             "2025-01-01 10:20:30"
@@ -234,8 +719,7 @@ mod tests {
             .format("%Y-%m-%d %H:%M:%S")
             .to_string();
         let args = vec!["doit", "--end", &start];
-        let command = build_command();
-        let args = Args::parse(command.get_matches_from(args));
+        let args = parse(&args);
         assert_eq!(args.start, now);
     }
 
@@ -248,8 +732,7 @@ mod tests {
             "--end",
             "2025-01-31 23:59:59",
         ];
-        let command = build_command();
-        let args = Args::parse(command.get_matches_from(args));
+        let args = parse(&args);
         assert_eq!(
             args.end.format("%Y-%m-%d %H:%M:%S").to_string(),
             "2025-01-31 23:59:59"
@@ -259,8 +742,7 @@ mod tests {
     #[test]
     fn test_parse_with_duration_seconds() {
         let args = vec!["doit", "--start", "2025-01-01 10:20:30", "--duration", "1s"];
-        let command = build_command();
-        let args = Args::parse(command.get_matches_from(args));
+        let args = parse(&args);
         assert_eq!(
             args.end.format("%Y-%m-%d %H:%M:%S").to_string(),
             "2025-01-01 10:20:31"
@@ -270,8 +752,7 @@ mod tests {
     #[test]
     fn test_parse_with_duration_minutes() {
         let args = vec!["doit", "--start", "2025-01-01 10:20:30", "--duration", "1m"];
-        let command = build_command();
-        let args = Args::parse(command.get_matches_from(args));
+        let args = parse(&args);
         assert_eq!(
             args.end.format("%Y-%m-%d %H:%M:%S").to_string(),
             "2025-01-01 10:21:30"
@@ -281,8 +762,7 @@ mod tests {
     #[test]
     fn test_parse_with_duration_hours() {
         let args = vec!["doit", "--start", "2025-01-01 10:20:30", "--duration", "1h"];
-        let command = build_command();
-        let args = Args::parse(command.get_matches_from(args));
+        let args = parse(&args);
         assert_eq!(
             args.end.format("%Y-%m-%d %H:%M:%S").to_string(),
             "2025-01-01 11:20:30"
@@ -292,8 +772,7 @@ mod tests {
     #[test]
     fn test_parse_with_duration_days() {
         let args = vec!["doit", "--start", "2025-01-01 10:20:30", "--duration", "1d"];
-        let command = build_command();
-        let args = Args::parse(command.get_matches_from(args));
+        let args = parse(&args);
         assert_eq!(
             args.end.format("%Y-%m-%d %H:%M:%S").to_string(),
             "2025-01-02 10:20:30"
@@ -482,8 +961,7 @@ mod tests {
         let test_cases = vec![("1", 1), ("10", 10), ("60", 60)];
         for (input, expected) in test_cases {
             let args = vec!["doit", "--duration", "9h", "--interval", input];
-            let command = build_command();
-            let args = Args::parse(command.get_matches_from(args));
+            let args = parse(&args);
             assert_eq!(args.interval, expected);
         }
     }
@@ -528,8 +1006,7 @@ mod tests {
             "--title",
             "My Custom Title",
         ];
-        let command = build_command();
-        let args = Args::parse(command.get_matches_from(args));
+        let args = parse(&args);
         assert_eq!(args.title, Some("My Custom Title".to_string()));
     }
 
@@ -544,8 +1021,7 @@ mod tests {
             "-t",
             "Short Title",
         ];
-        let command = build_command();
-        let args = Args::parse(command.get_matches_from(args));
+        let args = parse(&args);
         assert_eq!(args.title, Some("Short Title".to_string()));
     }
 
@@ -558,8 +1034,7 @@ mod tests {
             "--end",
             "2025-01-31 23:59:59",
         ];
-        let command = build_command();
-        let args = Args::parse(command.get_matches_from(args));
+        let args = parse(&args);
         assert_eq!(args.title, None);
     }
 
@@ -574,8 +1049,7 @@ mod tests {
             "--style",
             "default",
         ];
-        let command = build_command();
-        let args = Args::parse(command.get_matches_from(args));
+        let args = parse(&args);
         assert_eq!(args.style, Style::Default);
     }
 
@@ -590,8 +1064,7 @@ mod tests {
             "--style",
             "retro",
         ];
-        let command = build_command();
-        let args = Args::parse(command.get_matches_from(args));
+        let args = parse(&args);
         assert_eq!(args.style, Style::Retro);
     }
 
@@ -606,8 +1079,7 @@ mod tests {
             "--style",
             "synthwave",
         ];
-        let command = build_command();
-        let args = Args::parse(command.get_matches_from(args));
+        let args = parse(&args);
         assert_eq!(args.style, Style::Synthwave);
     }
 
@@ -622,8 +1094,337 @@ mod tests {
             "--style",
             "hourglass",
         ];
-        let command = build_command();
-        let args = Args::parse(command.get_matches_from(args));
+        let args = parse(&args);
         assert_eq!(args.style, Style::Hourglass);
     }
+
+    #[test]
+    fn test_parse_with_dashboard() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(&mut file, b"[calendar]\nfiscal_year_start = 7\n").unwrap();
+        let path = file.path().to_str().unwrap().to_string();
+        let args = vec![
+            "doit",
+            "--start",
+            "2025-01-01 10:00:00",
+            "--dashboard",
+            "--config",
+            &path,
+        ];
+        let args = Args::parse(build_command().get_matches_from(args));
+        assert!(args.dashboard);
+        assert_eq!(args.config.calendar.fiscal_year_start, 7);
+        assert_eq!(args.end - args.start, Duration::days(1));
+        assert_eq!(args.output, Output::Tui);
+
+        for extra in [["--once"], ["--inline"], ["--output=ndjson"]] {
+            let args = [&["doit", "--dashboard"][..], &extra].concat();
+            assert!(
+                build_command().try_get_matches_from(args).is_err(),
+                "{extra:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_with_preset() {
+        let args = vec!["doit", "--preset", "hour"];
+        let args = parse(&args);
+        assert_eq!(args.end - args.start, Duration::hours(1));
+        assert_eq!(args.start.minute(), 0);
+        assert_eq!(args.start.second(), 0);
+
+        let args = vec!["doit", "-p", "today"];
+        let args = parse(&args);
+        assert_eq!(args.end - args.start, Duration::days(1));
+
+        let args = vec!["doit", "--preset", "week", "--duration", "1h"];
+        assert!(build_command().try_get_matches_from(args).is_err());
+        let args = vec!["doit", "--preset", "fortnight"];
+        assert!(build_command().try_get_matches_from(args).is_err());
+    }
+
+    #[test]
+    fn test_parse_timer() {
+        let timer = parse_timer("Deploy window=2025-09-03 14:00..2025-09-03 16:00").unwrap();
+        assert_eq!(timer.name, "Deploy window");
+        assert_eq!(
+            timer.timespan.format_from_with_string("%Y-%m-%d %H:%M:%S"),
+            "2025-09-03 14:00:00"
+        );
+        assert_eq!(
+            timer.timespan.format_to_with_string("%Y-%m-%d %H:%M:%S"),
+            "2025-09-03 16:00:59"
+        );
+        let timer = parse_timer("Standup = 15m").unwrap();
+        assert_eq!(timer.name, "Standup");
+        assert_eq!(timer.timespan.duration, Duration::minutes(15));
+        for input in [
+            "Standup",
+            "=15m",
+            "Standup=soon",
+            "Standup=2025-09-03..2025-09-01",
+        ] {
+            assert!(parse_timer(input).is_err(), "input: {input}");
+        }
+    }
+
+    #[test]
+    fn test_parse_with_timers() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(
+            &mut file,
+            b"# Deadlines\nSprint end=2099-09-12 18:00\n\nRetro=2099-09-12 16:00\n",
+        )
+        .unwrap();
+        let path = file.path().to_str().unwrap().to_string();
+        let args = vec![
+            "doit",
+            "--timer",
+            "Standup=15m",
+            "--timer-file",
+            &path,
+            "--timer",
+            "Deploy window=3h",
+        ];
+        let args = parse(&args);
+        let names: Vec<_> = args.timers.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["Standup", "Deploy window", "Retro", "Sprint end"]);
+        // The next deadline is the span of --once and the status outputs
+        assert_eq!(args.end - args.start, Duration::minutes(15));
+
+        std::io::Write::write_all(&mut file, b"Lunch\n").unwrap();
+        let args = vec!["doit", "--timer-file", &path];
+        assert!(build_command().try_get_matches_from(args).is_err());
+    }
+
+    #[test]
+    fn test_parse_with_once() {
+        let args = vec!["doit", "-d", "1h", "--once", "--format", "json"];
+        let args = parse(&args);
+        assert!(args.once);
+        assert_eq!(args.format, Format::Json);
+
+        let args = vec!["doit", "-d", "1h", "--once"];
+        let args = parse(&args);
+        assert_eq!(args.format, Format::Text);
+
+        let args = vec!["doit", "-d", "1h", "--format", "json"];
+        assert!(build_command().try_get_matches_from(args).is_err());
+        let args = vec!["doit", "-d", "1h", "--once", "--format", "yaml"];
+        assert!(build_command().try_get_matches_from(args).is_err());
+    }
+
+    #[test]
+    fn test_parse_with_output() {
+        let args = vec!["doit", "-d", "1h", "--output", "text"];
+        let args = parse(&args);
+        assert_eq!(args.output, Output::Text);
+
+        let args = vec!["doit", "-d", "1h", "--output", "ndjson"];
+        let args = parse(&args);
+        assert_eq!(args.output, Output::Ndjson);
+
+        let args = vec!["doit", "-d", "1h", "--output", "swaybar"];
+        let args = parse(&args);
+        assert_eq!(args.output, Output::I3bar);
+
+        let args = vec!["doit", "-d", "1h", "--once", "--format", "waybar"];
+        let args = parse(&args);
+        assert_eq!(args.format, Format::Waybar);
+
+        let args = vec!["doit", "-d", "1h", "--output", "ndjson", "--once"];
+        assert!(build_command().try_get_matches_from(args).is_err());
+    }
+
+    #[test]
+    fn test_parse_tmux() {
+        let matches = build_command().get_matches_from(["doit", "tmux", "--width", "12"]);
+        let (name, matches) = matches.subcommand().unwrap();
+        assert_eq!(name, "tmux");
+        assert_eq!(matches.get_one::<usize>("width"), Some(&12));
+        assert!(!matches.get_flag("snippet"));
+
+        let args = vec!["doit", "-d", "1h", "tmux"];
+        assert!(build_command().try_get_matches_from(args).is_err());
+    }
+
+    #[test]
+    fn test_parse_with_notify() {
+        let args = vec!["doit", "-d", "1h"];
+        let args = parse(&args);
+        assert_eq!(args.notify, None);
+
+        let args = vec!["doit", "-d", "1h", "--notify", "--notify-at", "15m,50%"];
+        let args = parse(&args);
+        assert_eq!(args.notify, Some(Backend::detect()));
+        assert_eq!(
+            args.milestones,
+            vec![
+                Threshold::Remaining(Duration::minutes(15)),
+                Threshold::Percent(50.0)
+            ]
+        );
+
+        let args = vec!["doit", "-d", "1h", "--notify", "notify-send"];
+        let args = parse(&args);
+        assert_eq!(args.notify, Some(Backend::NotifySend));
+
+        let args = vec!["doit", "-d", "1h", "--notify-at", "off"];
+        assert!(build_command().try_get_matches_from(args).is_err());
+    }
+
+    #[test]
+    fn test_parse_with_cues() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let sound = file.path().to_str().unwrap().to_string();
+        let args = vec![
+            "doit", "-d", "1h", "--bell", "--sound", &sound, "--cue-at", "5m",
+        ];
+        let args = parse(&args);
+        assert!(args.bell);
+        assert_eq!(args.sound, Some(file.path().to_path_buf()));
+        assert_eq!(
+            args.cue_milestones,
+            vec![Threshold::Remaining(Duration::minutes(5))]
+        );
+
+        let args = vec!["doit", "-d", "1h", "--cue-at", "5m"];
+        assert!(build_command().try_get_matches_from(args).is_err());
+
+        let args = vec!["doit", "-d", "1h", "--sound", "/nonexistent/bell.wav"];
+        assert!(build_command().try_get_matches_from(args).is_err());
+    }
+
+    #[test]
+    fn test_parse_prompt() {
+        let matches = build_command().get_matches_from(["doit", "prompt"]);
+        assert_eq!(matches.subcommand_name(), Some("prompt"));
+    }
+
+    #[test]
+    fn test_parse_with_theme() {
+        let args = vec![
+            "doit",
+            "--duration",
+            "9h",
+            "--style",
+            "retro",
+            "--theme",
+            "synthwave",
+            "--color",
+            "truecolor",
+        ];
+        let args = parse(&args);
+        let synthwave = Theme::for_style(Style::Synthwave);
+        assert_eq!(args.theme.palette, synthwave.palette);
+        assert_eq!(args.theme.glyphs.horizontal, synthwave.glyphs.horizontal);
+    }
+
+    #[test]
+    fn test_parse_with_theme_file() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(&mut file, b"[glyphs]\nfilled = \"#\"\n").unwrap();
+        let path = file.path().to_str().unwrap();
+        let args = vec!["doit", "--duration", "9h", "--theme", path];
+        let args = parse(&args);
+        assert_eq!(args.theme.glyphs.filled, '#');
+        assert_eq!(args.theme.glyphs.empty, '░');
+    }
+
+    #[test]
+    fn test_parse_with_unknown_theme() {
+        let args = vec!["doit", "--duration", "9h", "--theme", "no-such-theme"];
+        let command = build_command();
+        let result = command.try_get_matches_from(args);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_with_color() {
+        let test_cases = [
+            ("never", ColorDepth::NoColor, Color::Reset),
+            ("16", ColorDepth::Ansi16, Color::DarkMagenta),
+            ("256", ColorDepth::Ansi256, Color::AnsiValue(198)),
+            (
+                "truecolor",
+                ColorDepth::TrueColor,
+                Color::Rgb {
+                    r: 238,
+                    g: 34,
+                    b: 125,
+                },
+            ),
+        ];
+        for (input, depth, bar) in test_cases {
+            let args = vec!["doit", "-d", "9h", "-S", "synthwave", "--color", input];
+            let args = parse(&args);
+            assert_eq!(args.color_depth, depth);
+            assert_eq!(args.theme.palette.bar, bar);
+        }
+        // Colour stays on at whatever depth the terminal has
+        let args = vec!["doit", "-d", "9h", "--color", "always"];
+        let args = parse(&args);
+        assert_ne!(args.color_depth, ColorDepth::NoColor);
+
+        let args = vec!["doit", "-d", "9h", "--color", "rainbow"];
+        assert!(build_command().try_get_matches_from(args).is_err());
+    }
+
+    #[test]
+    fn test_parse_with_bar() {
+        let args = vec!["doit", "-d", "9h"];
+        let args = parse(&args);
+        assert_eq!(args.theme.glyphs.bar, BarMode::Smooth);
+
+        let args = vec!["doit", "-d", "9h", "--theme", "mono"];
+        let args = parse(&args);
+        assert_eq!(args.theme.glyphs.bar, BarMode::Ascii);
+
+        let args = vec!["doit", "-d", "9h", "--theme", "mono", "--bar", "block"];
+        let args = parse(&args);
+        assert_eq!(args.theme.glyphs.bar, BarMode::Block);
+
+        let args = vec!["doit", "-d", "9h", "--bar", "round"];
+        assert!(build_command().try_get_matches_from(args).is_err());
+    }
+
+    #[test]
+    fn test_parse_threshold() {
+        assert_eq!(
+            parse_threshold("10m"),
+            Ok(Some(Threshold::Remaining(Duration::minutes(10))))
+        );
+        assert_eq!(parse_threshold("5%"), Ok(Some(Threshold::Percent(5.0))));
+        assert_eq!(parse_threshold("12.5%"), Ok(Some(Threshold::Percent(12.5))));
+        assert_eq!(parse_threshold("off"), Ok(None));
+        for input in ["", "10", "101%", "-1%", "x%", "10x"] {
+            assert!(parse_threshold(input).is_err(), "input: {input}");
+        }
+    }
+
+    #[test]
+    fn test_parse_with_thresholds() {
+        let args = vec!["doit", "-d", "9h"];
+        let args = parse(&args);
+        assert_eq!(
+            args.thresholds,
+            Thresholds {
+                warning: Some(Threshold::Remaining(Duration::minutes(10))),
+                critical: Some(Threshold::Remaining(Duration::minutes(2))),
+            }
+        );
+
+        assert_eq!(args.thresholds, Thresholds::default());
+
+        let args = vec!["doit", "-d", "9h", "--warn", "20%", "--critical", "off"];
+        let args = parse(&args);
+        assert_eq!(
+            args.thresholds,
+            Thresholds {
+                warning: Some(Threshold::Percent(20.0)),
+                critical: None,
+            }
+        );
+    }
 }
@@ -0,0 +1,278 @@
+use crossterm::{style::Color, tty::IsTty};
+use std::{env, io::stdout};
+
+// xterm's default RGB values for the 16 named colours, in ANSI index order
+const ANSI_16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::DarkRed, (128, 0, 0)),
+    (Color::DarkGreen, (0, 128, 0)),
+    (Color::DarkYellow, (128, 128, 0)),
+    (Color::DarkBlue, (0, 0, 128)),
+    (Color::DarkMagenta, (128, 0, 128)),
+    (Color::DarkCyan, (0, 128, 128)),
+    (Color::Grey, (192, 192, 192)),
+    (Color::DarkGrey, (128, 128, 128)),
+    (Color::Red, (255, 0, 0)),
+    (Color::Green, (0, 255, 0)),
+    (Color::Yellow, (255, 255, 0)),
+    (Color::Blue, (0, 0, 255)),
+    (Color::Magenta, (255, 0, 255)),
+    (Color::Cyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+// Channel levels of the 6x6x6 colour cube in the 256-colour palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Number of colours the output terminal can show, from none to 24-bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorDepth {
+    NoColor,
+    Ansi16,
+    Ansi256,
+    TrueColor,
+}
+
+impl ColorDepth {
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "auto" => Some(Self::detect()),
+            "always" => Some(Self::detect_forced()),
+            "never" | "none" => Some(ColorDepth::NoColor),
+            "16" => Some(ColorDepth::Ansi16),
+            "256" => Some(ColorDepth::Ansi256),
+            "truecolor" | "24bit" => Some(ColorDepth::TrueColor),
+            _ => None,
+        }
+    }
+
+    /// Detects the colour depth of stdout from the environment, honouring
+    /// `NO_COLOR`, `CLICOLOR` and `CLICOLOR_FORCE`.
+    #[must_use]
+    pub fn detect() -> Self {
+        Self::detect_with(|key| env::var(key).ok(), stdout().is_tty(), false)
+    }
+
+    /// Detects the colour depth as [`ColorDepth::detect`] does, but keeps
+    /// colour on even off a terminal or with `NO_COLOR`, for `--color always`.
+    #[must_use]
+    pub fn detect_forced() -> Self {
+        Self::detect_with(|key| env::var(key).ok(), stdout().is_tty(), true)
+    }
+
+    fn detect_with<F>(var: F, is_tty: bool, force: bool) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let is_set = |key: &str| var(key).is_some_and(|v| !v.is_empty());
+        if is_set("NO_COLOR") && !force {
+            return ColorDepth::NoColor;
+        }
+        let forced = force || var("CLICOLOR_FORCE").is_some_and(|v| !v.is_empty() && v != "0");
+        if !forced && (!is_tty || var("CLICOLOR").as_deref() == Some("0")) {
+            return ColorDepth::NoColor;
+        }
+        let term = var("TERM").unwrap_or_default();
+        if let Some(colorterm) = var("COLORTERM") {
+            if matches!(colorterm.as_str(), "truecolor" | "24bit") {
+                return ColorDepth::TrueColor;
+            }
+        }
+        if is_set("WT_SESSION") {
+            return ColorDepth::TrueColor;
+        }
+        match var("TERM_PROGRAM").as_deref() {
+            Some("iTerm.app" | "WezTerm" | "vscode") => return ColorDepth::TrueColor,
+            Some("Apple_Terminal") => return ColorDepth::Ansi256,
+            _ => {}
+        }
+        if term.contains("truecolor") || term.contains("direct") {
+            ColorDepth::TrueColor
+        } else if term.contains("256") {
+            ColorDepth::Ansi256
+        } else if term == "dumb" && !forced {
+            ColorDepth::NoColor
+        } else {
+            ColorDepth::Ansi16
+        }
+    }
+
+    /// Maps a colour to the nearest one this depth can show.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    pub fn map(self, color: Color) -> Color {
+        match (self, color) {
+            (_, Color::Reset) | (ColorDepth::TrueColor, _) => color,
+            (ColorDepth::NoColor, _) => Color::Reset,
+            (ColorDepth::Ansi256, Color::Rgb { r, g, b }) => Color::AnsiValue(nearest_256(r, g, b)),
+            (ColorDepth::Ansi256, _) => color,
+            (ColorDepth::Ansi16, Color::AnsiValue(value)) => {
+                let (r, g, b) = ansi_256_to_rgb(value);
+                nearest_16(r, g, b)
+            }
+            (ColorDepth::Ansi16, Color::Rgb { r, g, b }) => nearest_16(r, g, b),
+            (ColorDepth::Ansi16, _) => color,
+        }
+    }
+}
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| u32::from(a.abs_diff(b)).pow(2);
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+fn nearest_16(r: u8, g: u8, b: u8) -> Color {
+    ANSI_16
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb, (r, g, b)))
+        .map_or(Color::Reset, |(color, _)| *color)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn nearest_256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |v: u8| {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, level)| level.abs_diff(v))
+            .map_or(0, |(i, _)| i as u8)
+    };
+    let (ri, gi, bi) = (level(r), level(g), level(b));
+    let cube = 16 + 36 * ri + 6 * gi + bi;
+    let cube_rgb = ansi_256_to_rgb(cube);
+
+    let average = ((u16::from(r) + u16::from(g) + u16::from(b)) / 3) as u8;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23);
+    let gray_rgb = ansi_256_to_rgb(gray);
+
+    if distance(gray_rgb, (r, g, b)) < distance(cube_rgb, (r, g, b)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+fn ansi_256_to_rgb(value: u8) -> (u8, u8, u8) {
+    match value {
+        0..=15 => ANSI_16[value as usize].1,
+        16..=231 => {
+            let i = value - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[(i / 6 % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + 10 * (value - 232);
+            (level, level, level)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn detect(vars: &[(&str, &str)], is_tty: bool) -> ColorDepth {
+        detect_with_force(vars, is_tty, false)
+    }
+
+    fn detect_with_force(vars: &[(&str, &str)], is_tty: bool, force: bool) -> ColorDepth {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect();
+        ColorDepth::detect_with(|key| vars.get(key).cloned(), is_tty, force)
+    }
+
+    #[test]
+    fn test_detect() {
+        let test_cases = [
+            (vec![], true, ColorDepth::Ansi16),
+            (vec![], false, ColorDepth::NoColor),
+            (vec![("TERM", "xterm-256color")], true, ColorDepth::Ansi256),
+            (
+                vec![("COLORTERM", "truecolor")],
+                true,
+                ColorDepth::TrueColor,
+            ),
+            (vec![("TERM", "dumb")], true, ColorDepth::NoColor),
+            (vec![("NO_COLOR", "1")], true, ColorDepth::NoColor),
+            (vec![("NO_COLOR", "")], true, ColorDepth::Ansi16),
+            (
+                vec![("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")],
+                true,
+                ColorDepth::NoColor,
+            ),
+            (vec![("CLICOLOR_FORCE", "1")], false, ColorDepth::Ansi16),
+            (vec![("CLICOLOR_FORCE", "0")], false, ColorDepth::NoColor),
+            (vec![("CLICOLOR", "0")], true, ColorDepth::NoColor),
+            (
+                vec![("CLICOLOR_FORCE", "1"), ("COLORTERM", "24bit")],
+                false,
+                ColorDepth::TrueColor,
+            ),
+        ];
+        for (vars, is_tty, expected) in test_cases {
+            assert_eq!(detect(&vars, is_tty), expected, "vars: {vars:?}");
+        }
+    }
+
+    #[test]
+    fn test_detect_forced() {
+        let test_cases = [
+            (vec![], false, ColorDepth::Ansi16),
+            (vec![("TERM", "xterm-256color")], false, ColorDepth::Ansi256),
+            (vec![("TERM", "dumb")], true, ColorDepth::Ansi16),
+            (vec![("NO_COLOR", "1")], false, ColorDepth::Ansi16),
+            (
+                vec![("COLORTERM", "truecolor")],
+                false,
+                ColorDepth::TrueColor,
+            ),
+        ];
+        for (vars, is_tty, expected) in test_cases {
+            assert_eq!(
+                detect_with_force(&vars, is_tty, true),
+                expected,
+                "vars: {vars:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_map() {
+        let pink = Color::Rgb {
+            r: 238,
+            g: 34,
+            b: 125,
+        };
+        assert_eq!(ColorDepth::TrueColor.map(pink), pink);
+        assert_eq!(ColorDepth::Ansi256.map(pink), Color::AnsiValue(198));
+        assert_eq!(ColorDepth::Ansi16.map(pink), Color::DarkMagenta);
+        assert_eq!(ColorDepth::NoColor.map(pink), Color::Reset);
+        assert_eq!(ColorDepth::Ansi16.map(Color::AnsiValue(51)), Color::Cyan);
+        assert_eq!(ColorDepth::Ansi16.map(Color::DarkCyan), Color::DarkCyan);
+        assert_eq!(ColorDepth::NoColor.map(Color::DarkCyan), Color::Reset);
+        assert_eq!(ColorDepth::Ansi16.map(Color::Reset), Color::Reset);
+    }
+
+    #[test]
+    fn test_nearest_256() {
+        assert_eq!(nearest_256(0, 0, 0), 16);
+        assert_eq!(nearest_256(255, 255, 255), 231);
+        assert_eq!(nearest_256(255, 0, 0), 196);
+        assert_eq!(nearest_256(128, 128, 128), 244);
+    }
+
+    #[test]
+    fn test_ansi_256_to_rgb() {
+        assert_eq!(ansi_256_to_rgb(9), (255, 0, 0));
+        assert_eq!(ansi_256_to_rgb(196), (255, 0, 0));
+        assert_eq!(ansi_256_to_rgb(232), (8, 8, 8));
+        assert_eq!(ansi_256_to_rgb(255), (238, 238, 238));
+    }
+}
@@ -0,0 +1,147 @@
+use crate::error::DoItError;
+use anyhow::{format_err, Result};
+use chrono::{NaiveTime, Weekday};
+use serde::{Deserialize, Deserializer};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+const CONFIG_ENV: &str = "DOIT_CONFIG";
+
+/// Settings read from `config.toml`. Every field is optional.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub calendar: CalendarConfig,
+}
+
+/// How the calendar is divided into working days, weeks and fiscal years.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CalendarConfig {
+    /// Month (1 to 12) in which the fiscal year and its first quarter start
+    pub fiscal_year_start: u32,
+    #[serde(deserialize_with = "deserialize_time")]
+    pub workday_start: NaiveTime,
+    #[serde(deserialize_with = "deserialize_time")]
+    pub workday_end: NaiveTime,
+    /// First day of the week, such as `monday` or `sun`
+    pub week_start: Weekday,
+}
+
+impl Default for CalendarConfig {
+    fn default() -> Self {
+        CalendarConfig {
+            fiscal_year_start: 1,
+            workday_start: NaiveTime::from_hms_opt(9, 0, 0).unwrap_or_default(),
+            workday_end: NaiveTime::from_hms_opt(17, 0, 0).unwrap_or_default(),
+            week_start: Weekday::Mon,
+        }
+    }
+}
+
+impl Config {
+    #[allow(clippy::missing_errors_doc)]
+    pub fn parse(source: &str, origin: &str) -> Result<Self> {
+        let invalid = |message: String| {
+            format_err!(DoItError::InvalidConfig {
+                origin: origin.to_string(),
+                message,
+            })
+        };
+        let config: Config =
+            toml::from_str(source).map_err(|e| invalid(e.message().to_string()))?;
+        let calendar = &config.calendar;
+        if !(1..=12).contains(&calendar.fiscal_year_start) {
+            return Err(invalid(format!(
+                "fiscal_year_start must be a month from 1 to 12, not {}",
+                calendar.fiscal_year_start
+            )));
+        }
+        if calendar.workday_start >= calendar.workday_end {
+            return Err(invalid(
+                "workday_start must be before workday_end".to_string(),
+            ));
+        }
+        Ok(config)
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    pub fn load(path: &Path) -> Result<Self> {
+        let source = fs::read_to_string(path)?;
+        Self::parse(&source, &path.display().to_string())
+    }
+
+    /// Loads the config file when there is one, or falls back to defaults.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn find() -> Result<Self> {
+        match config_path() {
+            Some(path) if path.is_file() => Self::load(&path),
+            _ => Ok(Config::default()),
+        }
+    }
+}
+
+/// Config file: `$DOIT_CONFIG`, or `doit/config.toml` under the platform
+/// config directory.
+#[must_use]
+pub fn config_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os(CONFIG_ENV) {
+        return Some(PathBuf::from(path));
+    }
+    dirs::config_dir().map(|dir| dir.join("doit").join("config.toml"))
+}
+
+fn deserialize_time<'de, D>(deserializer: D) -> std::result::Result<NaiveTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    NaiveTime::parse_from_str(&s, "%H:%M")
+        .map_err(|_| serde::de::Error::custom(format!("invalid time '{s}', expected HH:MM")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(
+            r#"
+            [calendar]
+            fiscal_year_start = 4
+            workday_start = "08:30"
+            week_start = "sunday"
+            "#,
+            "test",
+        )
+        .unwrap();
+        assert_eq!(config.calendar.fiscal_year_start, 4);
+        assert_eq!(
+            config.calendar.workday_start,
+            NaiveTime::from_hms_opt(8, 30, 0).unwrap()
+        );
+        assert_eq!(
+            config.calendar.workday_end,
+            CalendarConfig::default().workday_end
+        );
+        assert_eq!(config.calendar.week_start, Weekday::Sun);
+        assert_eq!(Config::parse("", "test").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_parse_with_failure() {
+        let test_cases = [
+            "[calendar]\nfiscal_year_start = 13",
+            "[calendar]\nworkday_start = \"9am\"",
+            "[calendar]\nworkday_start = \"18:00\"",
+            "[calendar]\nweek_start = \"someday\"",
+            "[clock]\nformat = 24",
+        ];
+        for source in test_cases {
+            assert!(Config::parse(source, "test").is_err(), "{source}");
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{
+    external::{on_path, spawn},
+    schedule::Schedule,
+    Progress, Threshold,
+};
+use anyhow::Result;
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    process::Command,
+};
+use tracing::debug;
+
+const BELL: char = '\x07';
+// Players tried in order on Linux and the BSDs
+const PLAYERS: [&str; 3] = ["pw-play", "paplay", "aplay"];
+
+/// The bell and sound of `--bell` and `--sound`, played once at each
+/// milestone.
+#[derive(Debug, Clone)]
+pub struct Cues {
+    bell: bool,
+    sound: Option<PathBuf>,
+    schedule: Schedule,
+}
+
+impl Cues {
+    #[must_use]
+    pub fn new(
+        bell: bool,
+        sound: Option<PathBuf>,
+        thresholds: &[Threshold],
+        progress: &Progress,
+    ) -> Self {
+        Cues {
+            bell,
+            sound,
+            schedule: Schedule::new(thresholds, progress),
+        }
+    }
+
+    /// Rings the bell on `terminal` and starts the sound when a milestone
+    /// has been reached since the last update. Milestones passed together
+    /// make a single cue.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn update<W: Write>(&mut self, progress: &Progress, terminal: &mut W) -> Result<()> {
+        if self.schedule.due(progress).is_empty() {
+            return Ok(());
+        }
+        if self.bell {
+            write!(terminal, "{BELL}")?;
+            terminal.flush()?;
+        }
+        if let Some(sound) = &self.sound {
+            play(sound);
+        }
+        Ok(())
+    }
+}
+
+// Plays in the background so that the timer keeps refreshing and quitting
+// never waits for the end of the sound; the player finishes a completion
+// sound on its own after doit exits
+fn play(sound: &Path) {
+    let Some(mut command) = player(sound) else {
+        debug!("no sound player found");
+        return;
+    };
+    if let Err(error) = spawn(&mut command) {
+        debug!(?error);
+    }
+}
+
+fn player(sound: &Path) -> Option<Command> {
+    if cfg!(target_os = "macos") {
+        let mut command = Command::new("afplay");
+        command.arg(sound);
+        return Some(command);
+    }
+    if cfg!(windows) {
+        let mut command = Command::new("powershell");
+        command
+            .args(["-NoProfile", "-Command"])
+            .arg(powershell_command(sound));
+        return Some(command);
+    }
+    PLAYERS.into_iter().find(|name| on_path(name)).map(|name| {
+        let mut command = Command::new(name);
+        command.arg(sound);
+        command
+    })
+}
+
+// A single-quoted PowerShell string only needs its quotes doubled
+fn powershell_command(sound: &Path) -> String {
+    let path = sound.display().to_string().replace('\'', "''");
+    format!("(New-Object Media.SoundPlayer '{path}').PlaySync()")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Timespan;
+    use chrono::{Duration, NaiveDateTime};
+
+    fn progress(now: &str) -> Progress {
+        let time = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+        Timespan::new(time("2025-09-03 09:00:00"), time("2025-09-03 09:10:00"))
+            .unwrap()
+            .progress(time(now))
+    }
+
+    #[test]
+    fn test_update() {
+        let thresholds = [
+            Threshold::Remaining(Duration::minutes(5)),
+            Threshold::Remaining(Duration::minutes(1)),
+        ];
+        let mut cues = Cues::new(true, None, &thresholds, &progress("2025-09-03 09:00:00"));
+        let mut terminal = Vec::new();
+        let test_cases = [
+            ("2025-09-03 09:04:59", ""),
+            ("2025-09-03 09:05:00", "\x07"),
+            ("2025-09-03 09:05:30", ""),
+            // Skips past 1m left and the end in one interval
+            ("2025-09-03 09:10:30", "\x07"),
+            ("2025-09-03 09:11:00", ""),
+        ];
+        for (now, expected) in test_cases {
+            terminal.clear();
+            cues.update(&progress(now), &mut terminal).unwrap();
+            assert_eq!(String::from_utf8_lossy(&terminal), expected, "{now}");
+        }
+    }
+
+    #[test]
+    fn test_powershell_command() {
+        assert_eq!(
+            powershell_command(Path::new("C:\\Users\\O'Brien\\ding.wav")),
+            "(New-Object Media.SoundPlayer 'C:\\Users\\O''Brien\\ding.wav').PlaySync()"
+        );
+    }
+}
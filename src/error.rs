@@ -8,4 +8,10 @@ pub enum DoItError {
         from: NaiveDateTime,
         to: NaiveDateTime,
     },
+    #[error("Theme '{}' was not found.", .name)]
+    ThemeNotFound { name: String },
+    #[error("Invalid theme {}: {}", .origin, .message)]
+    InvalidTheme { origin: String, message: String },
+    #[error("Invalid config {}: {}", .origin, .message)]
+    InvalidConfig { origin: String, message: String },
 }
@@ -0,0 +1,33 @@
+use anyhow::Result;
+use std::{
+    env,
+    process::{Command, Stdio},
+    thread,
+};
+use tracing::debug;
+
+/// Whether `program` is an executable in one of the `PATH` directories.
+#[must_use]
+pub fn on_path(program: &str) -> bool {
+    env::var_os("PATH")
+        .is_some_and(|paths| env::split_paths(&paths).any(|dir| dir.join(program).is_file()))
+}
+
+/// Starts `command` and returns at once, so that a slow program never holds
+/// up a refresh. A thread waits for it and logs a failing exit status. No
+/// output is piped back, so the program carries on even if doit exits first.
+#[allow(clippy::missing_errors_doc)]
+pub fn spawn(command: &mut Command) -> Result<()> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    thread::spawn(move || match child.wait() {
+        Ok(status) if !status.success() => debug!("{program}: {status}"),
+        Ok(_) => {}
+        Err(error) => debug!(?error),
+    });
+    Ok(())
+}
@@ -0,0 +1,212 @@
+use crate::{config::CalendarConfig, Timespan};
+use anyhow::Result;
+use chrono::{Datelike, Days, Months, NaiveDate, NaiveDateTime, NaiveTime};
+
+/// A calendar period around the current time, such as this week or this
+/// quarter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Horizon {
+    /// Today's working hours
+    Workday,
+    /// The week, from its configured first day
+    Week,
+    Month,
+    /// A quarter of the fiscal year
+    Quarter,
+    /// The fiscal year, a calendar year unless configured otherwise
+    Year,
+}
+
+impl Horizon {
+    /// Horizons of the dashboard, from the shortest to the longest.
+    pub const ALL: [Horizon; 5] = [
+        Horizon::Workday,
+        Horizon::Week,
+        Horizon::Month,
+        Horizon::Quarter,
+        Horizon::Year,
+    ];
+
+    /// The period of this horizon that contains `now`.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn timespan(self, now: NaiveDateTime, calendar: &CalendarConfig) -> Result<Timespan> {
+        let today = now.date();
+        let (from, to) = match self {
+            Horizon::Workday => (
+                today.and_time(calendar.workday_start),
+                today.and_time(calendar.workday_end),
+            ),
+            Horizon::Week => {
+                let first = today.week(calendar.week_start).first_day();
+                midnights(first, first + Days::new(7))
+            }
+            Horizon::Month => {
+                let first = today.with_day(1).unwrap_or(today);
+                midnights(first, first + Months::new(1))
+            }
+            Horizon::Quarter => {
+                let (year_start, months) = fiscal_year_start(today, calendar);
+                let first = year_start + Months::new(months / 3 * 3);
+                midnights(first, first + Months::new(3))
+            }
+            Horizon::Year => {
+                let (year_start, _) = fiscal_year_start(today, calendar);
+                midnights(year_start, year_start + Months::new(12))
+            }
+        };
+        Timespan::new(from, to)
+    }
+
+    /// Short name of the period, such as `Week 36`, `September` or `Q3`.
+    #[must_use]
+    pub fn label(self, timespan: &Timespan, calendar: &CalendarConfig) -> String {
+        let from = timespan.from.date();
+        match self {
+            Horizon::Workday => "Today".to_string(),
+            // Numbered by the ISO week holding most of its days
+            Horizon::Week => format!("Week {}", (from + Days::new(3)).iso_week().week()),
+            Horizon::Month => from.format("%B").to_string(),
+            Horizon::Quarter => {
+                let (_, months) = fiscal_year_start(from, calendar);
+                format!("Q{}", months / 3 + 1)
+            }
+            Horizon::Year if calendar.fiscal_year_start == 1 => from.year().to_string(),
+            Horizon::Year => format!("FY{}/{:02}", from.year(), (from.year() + 1) % 100),
+        }
+    }
+}
+
+fn midnights(from: NaiveDate, to: NaiveDate) -> (NaiveDateTime, NaiveDateTime) {
+    (from.and_time(NaiveTime::MIN), to.and_time(NaiveTime::MIN))
+}
+
+// First day of the fiscal year containing `date`, and the whole months since
+fn fiscal_year_start(date: NaiveDate, calendar: &CalendarConfig) -> (NaiveDate, u32) {
+    let start_month = calendar.fiscal_year_start;
+    let year = if date.month() >= start_month {
+        date.year()
+    } else {
+        date.year() - 1
+    };
+    let start = NaiveDate::from_ymd_opt(year, start_month, 1).unwrap_or(date);
+    let months = (date.month() + 12 - start_month) % 12;
+    (start, months)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_timespan() {
+        let calendar = CalendarConfig::default();
+        let now = time("2025-09-03 10:30:00");
+        let test_cases = [
+            (
+                Horizon::Workday,
+                "2025-09-03 09:00:00",
+                "2025-09-03 17:00:00",
+                "Today",
+            ),
+            (
+                Horizon::Week,
+                "2025-09-01 00:00:00",
+                "2025-09-08 00:00:00",
+                "Week 36",
+            ),
+            (
+                Horizon::Month,
+                "2025-09-01 00:00:00",
+                "2025-10-01 00:00:00",
+                "September",
+            ),
+            (
+                Horizon::Quarter,
+                "2025-07-01 00:00:00",
+                "2025-10-01 00:00:00",
+                "Q3",
+            ),
+            (
+                Horizon::Year,
+                "2025-01-01 00:00:00",
+                "2026-01-01 00:00:00",
+                "2025",
+            ),
+        ];
+        for (horizon, from, to, label) in test_cases {
+            let timespan = horizon.timespan(now, &calendar).unwrap();
+            assert_eq!(timespan.from, time(from), "{horizon:?}");
+            assert_eq!(timespan.to, time(to), "{horizon:?}");
+            assert_eq!(horizon.label(&timespan, &calendar), label);
+        }
+    }
+
+    #[test]
+    fn test_timespan_with_fiscal_year() {
+        let calendar = CalendarConfig {
+            fiscal_year_start: 4,
+            ..CalendarConfig::default()
+        };
+        let test_cases = [
+            (
+                "2025-09-03 10:30:00",
+                Horizon::Quarter,
+                "2025-07-01",
+                "2025-10-01",
+                "Q2",
+            ),
+            (
+                "2025-09-03 10:30:00",
+                Horizon::Year,
+                "2025-04-01",
+                "2026-04-01",
+                "FY2025/26",
+            ),
+            (
+                "2026-02-14 10:30:00",
+                Horizon::Quarter,
+                "2026-01-01",
+                "2026-04-01",
+                "Q4",
+            ),
+            (
+                "2026-02-14 10:30:00",
+                Horizon::Year,
+                "2025-04-01",
+                "2026-04-01",
+                "FY2025/26",
+            ),
+            (
+                "2026-04-01 00:00:00",
+                Horizon::Quarter,
+                "2026-04-01",
+                "2026-07-01",
+                "Q1",
+            ),
+        ];
+        for (now, horizon, from, to, label) in test_cases {
+            let timespan = horizon.timespan(time(now), &calendar).unwrap();
+            assert_eq!(timespan.from, time(&format!("{from} 00:00:00")), "{now}");
+            assert_eq!(timespan.to, time(&format!("{to} 00:00:00")), "{now}");
+            assert_eq!(horizon.label(&timespan, &calendar), label, "{now}");
+        }
+    }
+
+    #[test]
+    fn test_timespan_with_week_start() {
+        let calendar = CalendarConfig {
+            week_start: chrono::Weekday::Sun,
+            ..CalendarConfig::default()
+        };
+        let timespan = Horizon::Week
+            .timespan(time("2025-09-03 10:30:00"), &calendar)
+            .unwrap();
+        assert_eq!(timespan.from, time("2025-08-31 00:00:00"));
+        assert_eq!(timespan.to, time("2025-09-07 00:00:00"));
+        assert_eq!(Horizon::Week.label(&timespan, &calendar), "Week 36");
+    }
+}
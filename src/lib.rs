@@ -1,12 +1,40 @@
 pub mod cli;
+pub mod color;
+pub mod config;
+pub mod cue;
 pub mod error;
+pub mod external;
+pub mod horizon;
+pub mod notify;
+pub mod osc;
+pub mod preset;
 pub mod progress;
+pub mod prompt;
 pub mod renderer;
+pub mod schedule;
+pub mod state;
+pub mod status;
+pub mod statusbar;
+pub mod theme;
+pub mod timer;
 pub mod timespan;
+pub mod tmux;
+pub mod urgency;
 
 pub use cli::{build_command, Args};
+pub use color::ColorDepth;
+pub use config::Config;
+pub use horizon::Horizon;
+pub use preset::Preset;
 pub use progress::Progress;
 pub use renderer::{
-    DefaultRenderer, HourglassRenderer, RetroRenderer, Style, StyledRenderer, SynthwaveRenderer,
+    BigClockRenderer, CalendarRenderer, DashboardRenderer, DefaultRenderer, GanttRenderer,
+    GraphRenderer, HourglassRenderer, InlineRenderer, RetroRenderer, RingRenderer, Style,
+    StyledRenderer, SynthwaveRenderer, TimersRenderer, VerticalRenderer,
 };
+pub use state::ActiveTimer;
+pub use status::{Format, Output, Status};
+pub use theme::Theme;
+pub use timer::Timer;
 pub use timespan::Timespan;
+pub use urgency::{Threshold, Thresholds, Urgency};
@@ -1,20 +1,37 @@
 use anyhow::Result;
-use chrono::Local;
+use chrono::{Local, NaiveDateTime, Timelike};
+use clap::ArgMatches;
 use crossterm::cursor::Hide;
 use crossterm::event::{poll, read, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
-use crossterm::terminal::{Clear, ClearType};
+use crossterm::style::{Attribute, Colored, Print, ResetColor, SetAttribute};
+use crossterm::terminal::{Clear, ClearType, SetTitle};
 use crossterm::{
     cursor::{MoveTo, Show},
     queue,
     terminal::{disable_raw_mode, enable_raw_mode},
 };
+use doit::cue::Cues;
+use doit::notify::Notifications;
+use doit::osc::{self, PopTitle, PushTitle, SetTaskbarProgress};
+use doit::state::state_path;
+use doit::status::State;
+use doit::statusbar::I3BAR_HEADER;
+use doit::timer::next_deadline;
 use doit::timespan::Timespan;
 use doit::{
-    build_command, Args, DefaultRenderer, HourglassRenderer, RetroRenderer, Style, StyledRenderer,
-    SynthwaveRenderer,
+    build_command, prompt, tmux, ActiveTimer, Args, BigClockRenderer, CalendarRenderer, ColorDepth,
+    DashboardRenderer, DefaultRenderer, Format, GanttRenderer, GraphRenderer, HourglassRenderer,
+    InlineRenderer, Progress, RetroRenderer, RingRenderer, Status, Style, StyledRenderer,
+    SynthwaveRenderer, Thresholds, TimersRenderer, VerticalRenderer,
 };
-use std::io::{stdout, Write};
-use std::time::Duration;
+use signal_hook::consts::{SIGINT, SIGTERM};
+use std::env;
+use std::io::{stderr, stdout, ErrorKind, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
 use tracing::debug;
 use tracing_subscriber::EnvFilter;
 
@@ -22,45 +39,314 @@ fn run<W>(w: &mut W) -> Result<()>
 where
     W: Write,
 {
-    let command = build_command();
-    let args = Args::parse(command.get_matches());
+    let matches = build_command().get_matches();
+    if let Some(("tmux", matches)) = matches.subcommand() {
+        return run_tmux(w, matches);
+    }
+    if matches.subcommand_name() == Some("prompt") {
+        return run_prompt(w);
+    }
+    let args = Args::parse(matches);
     #[cfg(debug_assertions)]
     debug!(?args);
     let timespan = Timespan::new(args.start.naive_utc(), args.end.naive_utc())?;
     #[cfg(debug_assertions)]
     debug!(?timespan);
+    Colored::set_ansi_color_disabled(args.color_depth == ColorDepth::NoColor);
+
+    if args.once {
+        let status = Status::new(args.title.clone(), timespan.progress(current_second()))
+            .with_thresholds(&args.thresholds);
+        writeln!(w, "{}", status.format(args.format)?)?;
+        return Ok(());
+    }
+
+    let state = save_active_timer(&args, &timespan);
+    let mut notifications = args.notify.map(|backend| {
+        Notifications::new(
+            backend.notifier(),
+            &args.milestones,
+            args.title.clone(),
+            &timespan.progress(Local::now().naive_local()),
+        )
+    });
+    let mut cues = (args.bell || args.sound.is_some()).then(|| {
+        Cues::new(
+            args.bell,
+            args.sound.clone(),
+            &args.cue_milestones,
+            &timespan.progress(Local::now().naive_local()),
+        )
+    });
+    let result = if let Some(format) = args.output.format() {
+        stream_loop(w, &args, &timespan, format, &mut notifications, &mut cues)
+    } else {
+        let mut row = 0;
+        setup_terminal(w, &args)?;
+        // Restore the terminal even when rendering fails part way
+        let result = render_loop(w, &args, &timespan, &mut row, &mut notifications, &mut cues);
+        reset_terminal(w, row, &args)?;
+        result
+    };
+    if let Some(path) = state {
+        ActiveTimer::clear(&path)?;
+    }
+    result
+}
+
+// Prints the saved timer as a tmux status segment, or nothing when no doit
+// is running
+fn run_tmux<W>(w: &mut W, matches: &ArgMatches) -> Result<()>
+where
+    W: Write,
+{
+    if matches.get_flag("snippet") {
+        let program = env::current_exe()?;
+        writeln!(w, "{}", tmux::snippet(&program.display().to_string()))?;
+        return Ok(());
+    }
+    let Some(timer) = load_active_timer()? else {
+        return Ok(());
+    };
+    let width = matches
+        .get_one::<usize>("width")
+        .copied()
+        .unwrap_or(tmux::DEFAULT_WIDTH);
+    let progress = timer.timespan()?.progress(current_second());
+    let line = tmux::status_line(&progress, width, &Thresholds::default());
+    writeln!(w, "{line}")?;
+    Ok(())
+}
+
+// Runs on every shell prompt, so it only reads the state file and stays
+// quiet when there is nothing to show, even if the file cannot be read or
+// holds a broken timespan
+fn run_prompt<W>(w: &mut W) -> Result<()>
+where
+    W: Write,
+{
+    let Ok(Some(timer)) = load_active_timer() else {
+        return Ok(());
+    };
+    let Ok(timespan) = timer.timespan() else {
+        return Ok(());
+    };
+    let progress = timespan.progress(current_second());
+    if let Some(segment) = prompt::segment(&progress) {
+        writeln!(w, "{segment}")?;
+    }
+    Ok(())
+}
 
-    let mut row;
-    setup_terminal(w)?;
+// Saves the timer on screen, or the next deadline of several, for `doit
+// tmux`; the timer keeps running even when the state cannot be written
+fn save_active_timer(args: &Args, timespan: &Timespan) -> Option<PathBuf> {
+    if args.dashboard {
+        return None;
+    }
+    let now = Local::now().naive_local();
+    let timer = match next_deadline(&args.timers, now) {
+        Some(timer) => ActiveTimer::new(Some(timer.name.clone()), &timer.timespan),
+        None => ActiveTimer::new(args.title.clone(), timespan),
+    };
+    let path = state_path()?;
+    match timer.save(&path) {
+        Ok(()) => Some(path),
+        Err(error) => {
+            debug!(?error);
+            None
+        }
+    }
+}
+
+fn load_active_timer() -> Result<Option<ActiveTimer>> {
+    match state_path() {
+        Some(path) => ActiveTimer::load_running(&path),
+        None => Ok(None),
+    }
+}
+
+fn render_loop<W>(
+    w: &mut W,
+    args: &Args,
+    timespan: &Timespan,
+    row: &mut u16,
+    notifications: &mut Option<Notifications>,
+    cues: &mut Option<Cues>,
+) -> Result<()>
+where
+    W: Write,
+{
     loop {
         let current_time = Local::now().naive_local();
         let progress = timespan.progress(current_time);
         debug!(?progress);
-        row = match args.style {
+        notify(notifications, &progress);
+        if let Some(cues) = cues {
+            cues.update(&progress, w)?;
+        }
+        let theme = args.theme.with_urgency(args.thresholds.urgency(&progress));
+        if args.window_title {
+            queue!(
+                w,
+                SetTitle(osc::window_title(args.title.as_deref(), &progress))
+            )?;
+        }
+        if args.taskbar {
+            let urgency = args.thresholds.urgency(&progress);
+            queue!(w, SetTaskbarProgress::new(&progress, urgency))?;
+        }
+        *row = match args.style {
+            _ if args.inline => {
+                let renderer =
+                    InlineRenderer::new(args.title.clone(), progress, theme).with_style(args.style);
+                renderer.render(w)?
+            }
+            Style::Gantt => {
+                let renderer = GanttRenderer::new(args.title.clone(), progress, theme)
+                    .with_timers(args.timers.clone())
+                    .with_thresholds(args.thresholds);
+                renderer.render(w)?
+            }
+            _ if !args.timers.is_empty() => {
+                let renderer = TimersRenderer::new(args.title.clone(), progress, theme)
+                    .with_timers(args.timers.clone())
+                    .with_thresholds(args.thresholds);
+                renderer.render(w)?
+            }
+            _ if args.dashboard => {
+                let renderer = DashboardRenderer::new(args.title.clone(), progress, theme)
+                    .with_calendar(args.config.calendar)
+                    .with_style(args.style);
+                renderer.render(w)?
+            }
             Style::Default => {
-                let renderer = DefaultRenderer::new(args.title.clone(), progress);
+                let renderer =
+                    DefaultRenderer::new(args.title.clone(), progress, theme).with_axis(args.axis);
                 renderer.render(w)?
             }
             Style::Hourglass => {
-                let renderer = HourglassRenderer::new(args.title.clone(), progress);
+                let renderer = HourglassRenderer::new(args.title.clone(), progress, theme);
                 renderer.render(w)?
             }
             Style::Retro => {
-                let renderer = RetroRenderer::new(args.title.clone(), progress);
+                let renderer = RetroRenderer::new(args.title.clone(), progress, theme);
                 renderer.render(w)?
             }
             Style::Synthwave => {
-                let renderer = SynthwaveRenderer::new(args.title.clone(), progress);
+                let renderer = SynthwaveRenderer::new(args.title.clone(), progress, theme);
+                renderer.render(w)?
+            }
+            Style::BigClock => {
+                let renderer = BigClockRenderer::new(args.title.clone(), progress, theme);
+                renderer.render(w)?
+            }
+            Style::Ring => {
+                let renderer = RingRenderer::new(args.title.clone(), progress, theme);
+                renderer.render(w)?
+            }
+            Style::Graph => {
+                let renderer = GraphRenderer::new(args.title.clone(), progress, theme)
+                    .with_ideal_line(args.ideal_line);
+                renderer.render(w)?
+            }
+            Style::Vertical => {
+                let renderer = VerticalRenderer::new(args.title.clone(), progress, theme);
+                renderer.render(w)?
+            }
+            Style::Calendar => {
+                let renderer = CalendarRenderer::new(args.title.clone(), progress, theme)
+                    .with_holidays(args.holidays.clone());
                 renderer.render(w)?
             }
         };
         w.flush()?;
         if listen_exit_event(args.interval)? {
-            break;
+            return Ok(());
         }
     }
-    reset_terminal(w, row)?;
-    Ok(())
+}
+
+// Writes status lines, leaving the terminal as it is, until the timespan
+// completes or a quit signal arrives: JSON on every tick, or any other
+// format whenever the line changes. Status bars keep the completed line
+// until they stop doit themselves
+fn stream_loop<W>(
+    w: &mut W,
+    args: &Args,
+    timespan: &Timespan,
+    format: Format,
+    notifications: &mut Option<Notifications>,
+    cues: &mut Option<Cues>,
+) -> Result<()>
+where
+    W: Write,
+{
+    let quit = Arc::new(AtomicBool::new(false));
+    for signal in [SIGINT, SIGTERM] {
+        signal_hook::flag::register(signal, Arc::clone(&quit))?;
+    }
+    // The i3bar protocol opens an endless array of status lines
+    if format == Format::I3bar {
+        match writeln!(w, "{I3BAR_HEADER}\n[").and_then(|()| w.flush()) {
+            Err(e) if e.kind() == ErrorKind::BrokenPipe => return Ok(()),
+            result => result?,
+        }
+    }
+    let mut last_line = None;
+    loop {
+        let quitting = quit.load(Ordering::Relaxed);
+        let status = Status::new(args.title.clone(), timespan.progress(current_second()))
+            .with_thresholds(&args.thresholds);
+        notify(notifications, &status.progress);
+        // The bell goes to the terminal, never into the piped status lines
+        if let Some(cues) = cues {
+            cues.update(&status.progress, &mut stderr())?;
+        }
+        let line = status.format(format)?;
+        if format == Format::Json || last_line.as_ref() != Some(&line) {
+            let separator = if format == Format::I3bar && last_line.is_some() {
+                ","
+            } else {
+                ""
+            };
+            match writeln!(w, "{separator}{line}").and_then(|()| w.flush()) {
+                // The reader has gone away, as with `| head -n 1`
+                Err(e) if e.kind() == ErrorKind::BrokenPipe => return Ok(()),
+                result => result?,
+            }
+            last_line = Some(line);
+        }
+        if quitting || (status.state == State::Complete && !format.is_status_bar()) {
+            return Ok(());
+        }
+        wait_unless_quit(&quit, args.interval);
+    }
+}
+
+// A missed notification is not worth stopping the timer for
+fn notify(notifications: &mut Option<Notifications>, progress: &Progress) {
+    if let Some(notifications) = notifications {
+        if let Err(error) = notifications.update(progress) {
+            debug!(?error);
+        }
+    }
+}
+
+fn wait_unless_quit(quit: &AtomicBool, timeout: u64) {
+    let deadline = Instant::now() + Duration::from_secs(timeout);
+    while !quit.load(Ordering::Relaxed) {
+        let left = deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return;
+        }
+        sleep(left.min(Duration::from_millis(100)));
+    }
+}
+
+fn current_second() -> NaiveDateTime {
+    let now = Local::now().naive_local();
+    now.with_nanosecond(0).unwrap_or(now)
 }
 
 #[allow(clippy::match_same_arms)]
@@ -89,21 +375,43 @@ fn listen_exit_event(timeout: u64) -> Result<bool> {
     Ok(false)
 }
 
-fn setup_terminal<W>(w: &mut W) -> Result<()>
+fn setup_terminal<W>(w: &mut W, args: &Args) -> Result<()>
 where
     W: Write,
 {
     enable_raw_mode()?;
-    queue!(w, Clear(ClearType::All), Hide)?;
+    // Inline mode draws at the cursor and keeps what is already on screen
+    if !args.inline {
+        queue!(w, Clear(ClearType::All))?;
+    }
+    if args.window_title {
+        queue!(w, PushTitle)?;
+    }
+    queue!(w, Hide)?;
     w.flush()?;
     Ok(())
 }
 
-fn reset_terminal<W>(w: &mut W, row: u16) -> Result<()>
+fn reset_terminal<W>(w: &mut W, row: u16, args: &Args) -> Result<()>
 where
     W: Write,
 {
-    queue!(w, MoveTo(0, row), Show)?;
+    queue!(w, ResetColor, SetAttribute(Attribute::Reset))?;
+    // An empty title makes terminals without a title stack fall back to
+    // their default one
+    if args.window_title {
+        queue!(w, SetTitle(""), PopTitle)?;
+    }
+    if args.taskbar {
+        queue!(w, SetTaskbarProgress::hidden())?;
+    }
+    // Leave the last inline line as it is and continue below it
+    if args.inline {
+        queue!(w, Print("\r\n"))?;
+    } else {
+        queue!(w, MoveTo(0, row))?;
+    }
+    queue!(w, Show)?;
     w.flush()?;
     disable_raw_mode()?;
     Ok(())
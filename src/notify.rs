@@ -0,0 +1,260 @@
+use crate::{
+    external::{on_path, spawn},
+    schedule::{Milestone, Schedule},
+    Progress, Threshold,
+};
+use anyhow::Result;
+use std::process::Command;
+
+const APP_NAME: &str = "doit";
+const GDBUS: &str = "gdbus";
+const NOTIFY_SEND: &str = "notify-send";
+
+/// How loudly a notification asks for attention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Priority {
+    Normal,
+    /// Stays on screen until dismissed with most notification daemons
+    Critical,
+}
+
+impl Priority {
+    // The `urgency` hint of the freedesktop specification
+    fn level(self) -> u8 {
+        match self {
+            Priority::Normal => 1,
+            Priority::Critical => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    pub summary: String,
+    pub body: String,
+    pub priority: Priority,
+}
+
+impl Notification {
+    /// The notification for a milestone, titled after the timer.
+    #[must_use]
+    pub fn new(title: Option<&str>, milestone: Milestone, progress: &Progress) -> Self {
+        let summary = title.unwrap_or(APP_NAME).to_string();
+        match milestone {
+            Milestone::Complete => Notification {
+                summary,
+                body: format!("Completed at {}", progress.timespan.format_to()),
+                priority: Priority::Critical,
+            },
+            Milestone::Threshold(_) => Notification {
+                summary,
+                body: format!(
+                    "{} left, {:.0}% done",
+                    progress.format_remaining(),
+                    progress.ratio * 100.0
+                ),
+                priority: Priority::Normal,
+            },
+        }
+    }
+}
+
+/// Something that shows desktop notifications. It is called from the
+/// refresh loop, so it must return without waiting for the notification.
+pub trait Notifier {
+    #[allow(clippy::missing_errors_doc)]
+    fn notify(&self, notification: &Notification) -> Result<()>;
+}
+
+/// Calls `org.freedesktop.Notifications.Notify` on the session bus.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DBusNotifier;
+
+impl Notifier for DBusNotifier {
+    fn notify(&self, notification: &Notification) -> Result<()> {
+        let hints = format!("{{'urgency': <byte {}>}}", notification.priority.level());
+        spawn(Command::new(GDBUS).args([
+            "call",
+            "--session",
+            "--dest",
+            "org.freedesktop.Notifications",
+            "--object-path",
+            "/org/freedesktop/Notifications",
+            "--method",
+            "org.freedesktop.Notifications.Notify",
+            APP_NAME,
+            "0",
+            "",
+            &notification.summary,
+            &notification.body,
+            "[]",
+            &hints,
+            "-1",
+        ]))
+    }
+}
+
+/// Runs `notify-send` from libnotify.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NotifySendNotifier;
+
+impl Notifier for NotifySendNotifier {
+    fn notify(&self, notification: &Notification) -> Result<()> {
+        let urgency = match notification.priority {
+            Priority::Normal => "normal",
+            Priority::Critical => "critical",
+        };
+        spawn(Command::new(NOTIFY_SEND).args([
+            "--app-name",
+            APP_NAME,
+            "--urgency",
+            urgency,
+            &notification.summary,
+            &notification.body,
+        ]))
+    }
+}
+
+/// Which program `--notify` sends notifications through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    DBus,
+    NotifySend,
+}
+
+impl Backend {
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "auto" => Some(Self::detect()),
+            "dbus" => Some(Backend::DBus),
+            "notify-send" => Some(Backend::NotifySend),
+            _ => None,
+        }
+    }
+
+    /// D-Bus when `gdbus` is on the `PATH`, `notify-send` otherwise.
+    #[must_use]
+    pub fn detect() -> Self {
+        if on_path(GDBUS) {
+            Backend::DBus
+        } else {
+            Backend::NotifySend
+        }
+    }
+
+    #[must_use]
+    pub fn notifier(self) -> Box<dyn Notifier> {
+        match self {
+            Backend::DBus => Box::new(DBusNotifier),
+            Backend::NotifySend => Box::new(NotifySendNotifier),
+        }
+    }
+}
+
+/// Sends a notification for each milestone as it is reached.
+pub struct Notifications {
+    notifier: Box<dyn Notifier>,
+    schedule: Schedule,
+    title: Option<String>,
+}
+
+impl Notifications {
+    #[must_use]
+    pub fn new(
+        notifier: Box<dyn Notifier>,
+        milestones: &[Threshold],
+        title: Option<String>,
+        progress: &Progress,
+    ) -> Self {
+        Notifications {
+            notifier,
+            schedule: Schedule::new(milestones, progress),
+            title,
+        }
+    }
+
+    /// Notifies of every milestone reached since the last update; a
+    /// notification that fails to start is not retried.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn update(&mut self, progress: &Progress) -> Result<()> {
+        for milestone in self.schedule.due(progress) {
+            let notification = Notification::new(self.title.as_deref(), milestone, progress);
+            self.notifier.notify(&notification)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Timespan;
+    use chrono::{Duration, NaiveDateTime};
+    use std::{cell::RefCell, rc::Rc};
+
+    // Records notifications instead of showing them
+    #[derive(Default, Clone)]
+    struct MockNotifier {
+        sent: Rc<RefCell<Vec<Notification>>>,
+    }
+
+    impl Notifier for MockNotifier {
+        fn notify(&self, notification: &Notification) -> Result<()> {
+            self.sent.borrow_mut().push(notification.clone());
+            Ok(())
+        }
+    }
+
+    fn progress(now: &str) -> Progress {
+        let time = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+        Timespan::new(time("2025-09-03 09:00"), time("2025-09-03 10:00"))
+            .unwrap()
+            .progress(time(now))
+    }
+
+    #[test]
+    fn test_update() {
+        let notifier = MockNotifier::default();
+        let milestones = [Threshold::Remaining(Duration::minutes(15))];
+        let mut notifications = Notifications::new(
+            Box::new(notifier.clone()),
+            &milestones,
+            Some("Focus".to_string()),
+            &progress("2025-09-03 09:00"),
+        );
+        let times = [
+            "2025-09-03 09:30",
+            "2025-09-03 09:45",
+            "2025-09-03 09:50",
+            "2025-09-03 10:00",
+            "2025-09-03 10:01",
+        ];
+        for now in times {
+            notifications.update(&progress(now)).unwrap();
+        }
+        assert_eq!(
+            *notifier.sent.borrow(),
+            vec![
+                Notification {
+                    summary: "Focus".to_string(),
+                    body: "15m left, 75% done".to_string(),
+                    priority: Priority::Normal,
+                },
+                Notification {
+                    summary: "Focus".to_string(),
+                    body: "Completed at 10:00".to_string(),
+                    priority: Priority::Critical,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_backend_from_name() {
+        assert_eq!(Backend::from_name("dbus"), Some(Backend::DBus));
+        assert_eq!(Backend::from_name("notify-send"), Some(Backend::NotifySend));
+        assert_eq!(Backend::from_name("auto"), Some(Backend::detect()));
+        assert_eq!(Backend::from_name("growl"), None);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{urgency::Urgency, Progress};
+use crossterm::Command;
+use std::fmt;
+
+const APP_NAME: &str = "doit";
+
+/// State of the OSC 9;4 progress indicator, as numbered by Windows Terminal
+/// and `ConEmu`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskbarState {
+    Hidden = 0,
+    Normal = 1,
+    Error = 2,
+    Paused = 4,
+}
+
+/// Shows `percent` in the tab or taskbar of terminals that understand
+/// OSC 9;4; others ignore it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetTaskbarProgress {
+    pub state: TaskbarState,
+    pub percent: u8,
+}
+
+impl SetTaskbarProgress {
+    /// Normal until the warning threshold, then paused (yellow) and error
+    /// (red), following the bar colour.
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn new(progress: &Progress, urgency: Urgency) -> Self {
+        let state = match urgency {
+            _ if progress.is_complete() => TaskbarState::Normal,
+            Urgency::Calm => TaskbarState::Normal,
+            Urgency::Warning => TaskbarState::Paused,
+            Urgency::Critical => TaskbarState::Error,
+        };
+        SetTaskbarProgress {
+            state,
+            percent: (progress.ratio * 100.0).round().min(100.0) as u8,
+        }
+    }
+
+    /// Removes the indicator.
+    #[must_use]
+    pub fn hidden() -> Self {
+        SetTaskbarProgress {
+            state: TaskbarState::Hidden,
+            percent: 0,
+        }
+    }
+}
+
+impl Command for SetTaskbarProgress {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        write!(f, "\x1B]9;4;{};{}\x07", self.state as u8, self.percent)
+    }
+
+    // The legacy console has no equivalent
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Saves the window title on the terminal's title stack (XTWINOPS 22).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PushTitle;
+
+impl Command for PushTitle {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        f.write_str("\x1B[22;0t")
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Restores the title saved by [`PushTitle`] (XTWINOPS 23).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PopTitle;
+
+impl Command for PopTitle {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        f.write_str("\x1B[23;0t")
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A window title such as `Work 42% · 1h 5m left`.
+#[must_use]
+pub fn window_title(title: Option<&str>, progress: &Progress) -> String {
+    let name = title.unwrap_or(APP_NAME);
+    if progress.is_complete() {
+        return format!("{name} Completed");
+    }
+    format!(
+        "{name} {:.0}% · {} left",
+        progress.ratio * 100.0,
+        progress.format_remaining()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Thresholds, Timespan};
+    use chrono::NaiveDateTime;
+
+    fn progress(now: &str) -> Progress {
+        let time = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+        Timespan::new(time("2025-09-03 09:00"), time("2025-09-03 10:00"))
+            .unwrap()
+            .progress(time(now))
+    }
+
+    fn ansi(command: impl Command) -> String {
+        let mut ansi = String::new();
+        command.write_ansi(&mut ansi).unwrap();
+        ansi
+    }
+
+    #[test]
+    fn test_set_taskbar_progress() {
+        let thresholds = Thresholds::default();
+        let test_cases = [
+            ("2025-09-03 09:15", "\x1B]9;4;1;25\x07"),
+            ("2025-09-03 09:55", "\x1B]9;4;4;91\x07"),
+            ("2025-09-03 09:59", "\x1B]9;4;2;98\x07"),
+            ("2025-09-03 10:30", "\x1B]9;4;1;100\x07"),
+        ];
+        for (now, expected) in test_cases {
+            let progress = progress(now);
+            let command = SetTaskbarProgress::new(&progress, thresholds.urgency(&progress));
+            assert_eq!(ansi(command), expected, "{now}");
+        }
+        assert_eq!(ansi(SetTaskbarProgress::hidden()), "\x1B]9;4;0;0\x07");
+    }
+
+    #[test]
+    fn test_window_title() {
+        assert_eq!(
+            window_title(Some("Work"), &progress("2025-09-03 09:15")),
+            "Work 25% · 45m left"
+        );
+        assert_eq!(
+            window_title(None, &progress("2025-09-03 10:00")),
+            "doit Completed"
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{config::CalendarConfig, horizon::Horizon, Timespan};
+use anyhow::Result;
+use chrono::{Days, Duration, NaiveDateTime, NaiveTime, Timelike};
+
+/// A named timespan around the current time, used instead of `--start` and
+/// `--end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preset {
+    /// Today's working hours
+    Workday,
+    /// Midnight to midnight
+    Today,
+    Week,
+    Month,
+    Quarter,
+    Year,
+    /// The current hour, until the next full hour
+    Hour,
+    /// The current half hour, until the next full or half hour
+    HalfHour,
+}
+
+impl Preset {
+    pub const NAMES: [&'static str; 8] = [
+        "workday",
+        "today",
+        "week",
+        "month",
+        "quarter",
+        "year",
+        "hour",
+        "half-hour",
+    ];
+
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "workday" => Some(Preset::Workday),
+            "today" | "day" => Some(Preset::Today),
+            "week" => Some(Preset::Week),
+            "month" => Some(Preset::Month),
+            "quarter" => Some(Preset::Quarter),
+            "year" => Some(Preset::Year),
+            "hour" => Some(Preset::Hour),
+            "half-hour" | "halfhour" => Some(Preset::HalfHour),
+            _ => None,
+        }
+    }
+
+    /// The period of this preset that contains `now`.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn timespan(self, now: NaiveDateTime, calendar: &CalendarConfig) -> Result<Timespan> {
+        let hour = now.date().and_time(NaiveTime::MIN) + Duration::hours(i64::from(now.hour()));
+        match self {
+            Preset::Workday => Horizon::Workday.timespan(now, calendar),
+            Preset::Today => {
+                let midnight = now.date().and_time(NaiveTime::MIN);
+                Timespan::new(midnight, midnight + Days::new(1))
+            }
+            Preset::Week => Horizon::Week.timespan(now, calendar),
+            Preset::Month => Horizon::Month.timespan(now, calendar),
+            Preset::Quarter => Horizon::Quarter.timespan(now, calendar),
+            Preset::Year => Horizon::Year.timespan(now, calendar),
+            Preset::Hour => Timespan::new(hour, hour + Duration::hours(1)),
+            Preset::HalfHour => {
+                let from = hour + Duration::minutes(i64::from(now.minute() / 30 * 30));
+                Timespan::new(from, from + Duration::minutes(30))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Weekday;
+
+    fn time(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_from_name() {
+        for name in Preset::NAMES {
+            assert!(Preset::from_name(name).is_some(), "{name}");
+        }
+        assert_eq!(Preset::from_name("Half-Hour"), Some(Preset::HalfHour));
+        assert_eq!(Preset::from_name("fortnight"), None);
+    }
+
+    #[test]
+    fn test_timespan() {
+        let calendar = CalendarConfig {
+            workday_start: NaiveTime::from_hms_opt(8, 30, 0).unwrap(),
+            week_start: Weekday::Sun,
+            ..CalendarConfig::default()
+        };
+        let now = time("2025-09-03 10:42:15");
+        let test_cases = [
+            (
+                Preset::Workday,
+                "2025-09-03 08:30:00",
+                "2025-09-03 17:00:00",
+            ),
+            (Preset::Today, "2025-09-03 00:00:00", "2025-09-04 00:00:00"),
+            (Preset::Week, "2025-08-31 00:00:00", "2025-09-07 00:00:00"),
+            (Preset::Month, "2025-09-01 00:00:00", "2025-10-01 00:00:00"),
+            (
+                Preset::Quarter,
+                "2025-07-01 00:00:00",
+                "2025-10-01 00:00:00",
+            ),
+            (Preset::Year, "2025-01-01 00:00:00", "2026-01-01 00:00:00"),
+            (Preset::Hour, "2025-09-03 10:00:00", "2025-09-03 11:00:00"),
+            (
+                Preset::HalfHour,
+                "2025-09-03 10:30:00",
+                "2025-09-03 11:00:00",
+            ),
+        ];
+        for (preset, from, to) in test_cases {
+            let timespan = preset.timespan(now, &calendar).unwrap();
+            assert_eq!(timespan.from, time(from), "{preset:?}");
+            assert_eq!(timespan.to, time(to), "{preset:?}");
+        }
+        let timespan = Preset::HalfHour
+            .timespan(time("2025-09-03 23:05:00"), &calendar)
+            .unwrap();
+        assert_eq!(timespan.from, time("2025-09-03 23:00:00"));
+        assert_eq!(timespan.to, time("2025-09-03 23:30:00"));
+    }
+}
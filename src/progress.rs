@@ -1,12 +1,17 @@
-use crate::timespan::Timespan;
+use crate::timespan::{serialize_seconds, Timespan};
 use chrono::{Duration, NaiveDateTime};
+use serde::Serialize;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub struct Progress {
+    #[serde(flatten)]
     pub timespan: Timespan,
+    #[serde(rename = "now")]
     pub current_time: NaiveDateTime,
     pub ratio: f64,
+    #[serde(serialize_with = "serialize_seconds")]
     pub elapsed: Duration,
+    #[serde(serialize_with = "serialize_seconds")]
     pub remaining: Duration,
 }
 
@@ -46,6 +51,15 @@ impl Progress {
         }
     }
 
+    /// Elapsed fraction without the 1% rounding of `ratio`, for drawing
+    /// at sub-cell resolution.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn precise_ratio(&self) -> f64 {
+        (self.elapsed.num_milliseconds() as f64 / self.timespan.duration.num_milliseconds() as f64)
+            .clamp(0.0, 1.0)
+    }
+
     #[must_use]
     pub fn format_remaining(&self) -> String {
         Timespan::format_duration_string(self.remaining)
@@ -130,4 +144,23 @@ mod tests {
         assert_eq!(progress.elapsed, timespan.duration);
         assert_eq!(progress.remaining, Duration::zero());
     }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_precise_ratio() {
+        let fmt = "%Y-%m-%d %H:%M:%S";
+        let from = NaiveDateTime::parse_from_str("2025-09-01 00:00:00", fmt).unwrap();
+        let to = NaiveDateTime::parse_from_str("2025-09-01 08:00:00", fmt).unwrap();
+        let timespan = Timespan::new(from, to).unwrap();
+
+        let progress = Progress::new(timespan, from + Duration::minutes(3));
+        assert_eq!(progress.ratio, 0.0);
+        assert_eq!(progress.precise_ratio(), 0.00625);
+
+        let progress = Progress::new(timespan, from - Duration::hours(1));
+        assert_eq!(progress.precise_ratio(), 0.0);
+
+        let progress = Progress::new(timespan, to + Duration::hours(1));
+        assert_eq!(progress.precise_ratio(), 1.0);
+    }
 }
@@ -0,0 +1,36 @@
+use crate::Progress;
+
+const ICON: char = '⏳';
+
+/// A shell prompt segment such as `⏳ 23m`, or `None` once the timer has
+/// completed so that the prompt goes back to normal.
+#[must_use]
+pub fn segment(progress: &Progress) -> Option<String> {
+    if progress.is_complete() {
+        return None;
+    }
+    Some(format!("{ICON} {}", progress.format_remaining()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Timespan;
+    use chrono::NaiveDateTime;
+
+    fn progress(now: &str) -> Progress {
+        let time = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+        Timespan::new(time("2025-09-03 09:00"), time("2025-09-03 10:00"))
+            .unwrap()
+            .progress(time(now))
+    }
+
+    #[test]
+    fn test_segment() {
+        assert_eq!(
+            segment(&progress("2025-09-03 09:37")),
+            Some("⏳ 23m".to_string())
+        );
+        assert_eq!(segment(&progress("2025-09-03 10:00")), None);
+    }
+}
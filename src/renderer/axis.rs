@@ -0,0 +1,363 @@
+use crate::{theme::Glyphs, Timespan};
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use std::iter;
+
+const TICK: char = '┬';
+const DAY_BOUNDARY: char = '╥';
+const NOW_CARET: char = '▲';
+const HOUR_FORMAT: &str = "%H:%M";
+const DATE_FORMAT: &str = "%m-%d";
+const MONTH_FORMAT: &str = "%Y-%m";
+// Blank cells kept between two labels
+const LABEL_GAP: usize = 1;
+
+/// Distance between two labelled ticks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Minutes(i64),
+    Days(i64),
+    Months(u32),
+}
+
+// Candidate steps from the finest to the coarsest
+const STEPS: [Step; 18] = [
+    Step::Minutes(1),
+    Step::Minutes(5),
+    Step::Minutes(10),
+    Step::Minutes(15),
+    Step::Minutes(30),
+    Step::Minutes(60),
+    Step::Minutes(120),
+    Step::Minutes(180),
+    Step::Minutes(360),
+    Step::Minutes(720),
+    Step::Days(1),
+    Step::Days(2),
+    Step::Days(7),
+    Step::Days(14),
+    Step::Months(1),
+    Step::Months(3),
+    Step::Months(6),
+    Step::Months(12),
+];
+
+impl Step {
+    fn format(self) -> &'static str {
+        match self {
+            Step::Minutes(_) => HOUR_FORMAT,
+            Step::Days(_) => DATE_FORMAT,
+            Step::Months(_) => MONTH_FORMAT,
+        }
+    }
+
+    /// Ticks from `from` to `to`, aligned to round times: multiples of the
+    /// step since midnight, midnights (Mondays for whole weeks), or the
+    /// first of a month. They are made one at a time, so that a long span
+    /// with a fine step costs nothing until iterated.
+    fn ticks(self, from: NaiveDateTime, to: NaiveDateTime) -> impl Iterator<Item = NaiveDateTime> {
+        let first = match self {
+            Step::Minutes(minutes) => {
+                let midnight = from.date().and_time(NaiveTime::MIN);
+                let step = minutes * 60;
+                let seconds = (from - midnight).num_seconds();
+                midnight + Duration::seconds((seconds + step - 1) / step * step)
+            }
+            Step::Days(days) => {
+                let mut date = next_midnight(from).date();
+                if days % 7 == 0 {
+                    while date.weekday() != Weekday::Mon {
+                        date = date.succ_opt().unwrap_or(date);
+                    }
+                }
+                date.and_time(NaiveTime::MIN)
+            }
+            Step::Months(months) => {
+                let date = next_midnight(from).date();
+                let mut month = NaiveDate::from_ymd_opt(date.year(), date.month(), 1)
+                    .unwrap_or(date)
+                    .and_time(NaiveTime::MIN);
+                while month < from || !month.month0().is_multiple_of(months) {
+                    month = month + Months::new(1);
+                }
+                month
+            }
+        };
+        iter::successors(Some(first), move |&tick| {
+            Some(match self {
+                Step::Minutes(minutes) => tick + Duration::minutes(minutes),
+                Step::Days(days) => tick + Duration::days(days),
+                Step::Months(months) => tick + Months::new(months),
+            })
+        })
+        .take_while(move |&tick| tick <= to)
+    }
+}
+
+/// A time axis drawn under a bar of the same width.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Axis {
+    /// The axis line with its ticks, day boundaries and the now caret
+    pub line: String,
+    /// Cell of the now caret, when now is within the timespan
+    pub caret: Option<usize>,
+    /// Time labels centred under their ticks
+    pub labels: String,
+}
+
+/// Builds an axis `width` cells wide for `timespan`, with as many labels as
+/// fit without overlapping.
+#[must_use]
+pub fn build_axis(
+    timespan: &Timespan,
+    current_time: NaiveDateTime,
+    width: usize,
+    glyphs: &Glyphs,
+) -> Axis {
+    let mut line = vec![glyphs.horizontal; width];
+    let mut labels = vec![' '; width];
+    if width == 0 {
+        return Axis {
+            line: String::new(),
+            caret: None,
+            labels: String::new(),
+        };
+    }
+    let step = choose_step(timespan, width);
+    let multi_day = timespan.duration >= Duration::days(1);
+
+    // Mark midnights while they stay at least two cells apart
+    let days = usize::try_from(timespan.duration.num_days()).unwrap_or(usize::MAX);
+    if multi_day && days.saturating_mul(2) <= width {
+        let mut midnight = next_midnight(timespan.from);
+        while midnight <= timespan.to {
+            line[position(timespan, midnight, width)] = DAY_BOUNDARY;
+            midnight += Duration::days(1);
+        }
+    }
+
+    let mut free_from = 0;
+    for tick in step.ticks(timespan.from, timespan.to) {
+        let cell = position(timespan, tick, width);
+        if line[cell] == glyphs.horizontal {
+            line[cell] = TICK;
+        }
+        // On multi-day spans, hour labels at midnight show the date instead
+        let format =
+            if multi_day && matches!(step, Step::Minutes(_)) && tick.time() == NaiveTime::MIN {
+                DATE_FORMAT
+            } else {
+                step.format()
+            };
+        let label: Vec<char> = Timespan::format_time_with_string(tick, format)
+            .chars()
+            .collect();
+        if label.len() > width {
+            continue;
+        }
+        let start = cell
+            .saturating_sub(label.len() / 2)
+            .min(width - label.len());
+        if start < free_from {
+            continue;
+        }
+        labels[start..start + label.len()].copy_from_slice(&label);
+        free_from = start + label.len() + LABEL_GAP;
+    }
+
+    let caret = (timespan.from..=timespan.to)
+        .contains(&current_time)
+        .then(|| position(timespan, current_time, width));
+    if let Some(cell) = caret {
+        line[cell] = NOW_CARET;
+    }
+    Axis {
+        line: line.into_iter().collect(),
+        caret,
+        labels: labels.into_iter().collect(),
+    }
+}
+
+// The finest step whose labels all fit in `width`, counting no further
+// than the first tick that does not fit
+fn choose_step(timespan: &Timespan, width: usize) -> Step {
+    STEPS
+        .into_iter()
+        .find(|step| {
+            let label_width = timespan.format_from_with_string(step.format()).len() + LABEL_GAP;
+            step.ticks(timespan.from, timespan.to)
+                .nth(width / label_width)
+                .is_none()
+        })
+        .unwrap_or(Step::Months(12))
+}
+
+fn next_midnight(time: NaiveDateTime) -> NaiveDateTime {
+    let midnight = time.date().and_time(NaiveTime::MIN);
+    if midnight < time {
+        midnight + Duration::days(1)
+    } else {
+        midnight
+    }
+}
+
+/// Cell of `time` on an axis `width` cells wide, the ends of `timespan`
+/// falling on the first and last cells.
+#[must_use]
+#[allow(clippy::cast_sign_loss)]
+#[allow(clippy::cast_precision_loss)]
+#[allow(clippy::cast_possible_truncation)]
+pub fn position(timespan: &Timespan, time: NaiveDateTime, width: usize) -> usize {
+    let ratio =
+        (time - timespan.from).num_seconds() as f64 / timespan.duration.num_seconds() as f64;
+    let last = width.saturating_sub(1);
+    ((ratio.clamp(0.0, 1.0) * last as f64).round() as usize).min(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Style, Theme};
+
+    fn timespan(from: &str, to: &str) -> Timespan {
+        let fmt = "%Y-%m-%d %H:%M:%S";
+        Timespan::new(
+            NaiveDateTime::parse_from_str(from, fmt).unwrap(),
+            NaiveDateTime::parse_from_str(to, fmt).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn time(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_choose_step() {
+        let test_cases = [
+            (
+                "2025-09-01 09:00:00",
+                "2025-09-01 10:00:00",
+                80,
+                Step::Minutes(5),
+            ),
+            (
+                "2025-09-01 09:00:00",
+                "2025-09-01 17:00:00",
+                80,
+                Step::Minutes(60),
+            ),
+            (
+                "2025-09-01 09:00:00",
+                "2025-09-01 17:00:00",
+                30,
+                Step::Minutes(120),
+            ),
+            (
+                "2025-09-01 00:00:00",
+                "2025-09-08 00:00:00",
+                80,
+                Step::Days(1),
+            ),
+            (
+                "2025-09-01 00:00:00",
+                "2025-10-01 00:00:00",
+                80,
+                Step::Days(7),
+            ),
+            (
+                "2025-01-01 00:00:00",
+                "2026-01-01 00:00:00",
+                80,
+                Step::Months(3),
+            ),
+            (
+                "2025-01-01 00:00:00",
+                "2030-01-01 00:00:00",
+                80,
+                Step::Months(12),
+            ),
+            (
+                "2000-01-01 00:00:00",
+                "2100-01-01 00:00:00",
+                120,
+                Step::Months(12),
+            ),
+        ];
+        for (from, to, width, expected) in test_cases {
+            assert_eq!(
+                choose_step(&timespan(from, to), width),
+                expected,
+                "{from} → {to} in {width}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ticks() {
+        let ticks: Vec<_> = Step::Minutes(15)
+            .ticks(time("2025-09-01 09:05:00"), time("2025-09-01 09:50:00"))
+            .collect();
+        assert_eq!(
+            ticks,
+            vec![
+                time("2025-09-01 09:15:00"),
+                time("2025-09-01 09:30:00"),
+                time("2025-09-01 09:45:00"),
+            ]
+        );
+        // Weekly ticks start on the first Monday
+        let ticks: Vec<_> = Step::Days(7)
+            .ticks(time("2025-09-03 12:00:00"), time("2025-09-20 00:00:00"))
+            .collect();
+        assert_eq!(
+            ticks,
+            vec![time("2025-09-08 00:00:00"), time("2025-09-15 00:00:00")]
+        );
+        let ticks: Vec<_> = Step::Months(3)
+            .ticks(time("2025-02-10 00:00:00"), time("2025-12-31 00:00:00"))
+            .collect();
+        assert_eq!(
+            ticks,
+            vec![
+                time("2025-04-01 00:00:00"),
+                time("2025-07-01 00:00:00"),
+                time("2025-10-01 00:00:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_axis_hours() {
+        let glyphs = Theme::for_style(Style::Default).glyphs;
+        let timespan = timespan("2025-09-01 09:00:00", "2025-09-01 13:00:00");
+        let axis = build_axis(&timespan, time("2025-09-01 10:00:00"), 33, &glyphs);
+        assert_eq!(axis.line, "┬───────▲───────┬───────┬───────┬");
+        assert_eq!(axis.caret, Some(8));
+        assert_eq!(axis.labels, "09:00 10:00   11:00   12:00 13:00");
+    }
+
+    #[test]
+    fn test_build_axis_days() {
+        let glyphs = Theme::for_style(Style::Default).glyphs;
+        let timespan = timespan("2025-09-01 12:00:00", "2025-09-03 12:00:00");
+        let axis = build_axis(&timespan, time("2025-09-05 00:00:00"), 40, &glyphs);
+        assert_eq!(axis.caret, None);
+        assert_eq!(axis.line, "┬─────────╥─────────┬────────╥─────────┬");
+        assert_eq!(axis.labels, "12:00   09-02     12:00    09-03   12:00");
+    }
+
+    #[test]
+    fn test_build_axis_decades() {
+        let glyphs = Theme::for_style(Style::Default).glyphs;
+        let timespan = timespan("2000-01-01 00:00:00", "2100-01-01 00:00:00");
+        let axis = build_axis(&timespan, time("2025-09-01 00:00:00"), 120, &glyphs);
+        assert_eq!(axis.line.chars().count(), 120);
+        assert_eq!(axis.caret, Some(31));
+        // Yearly ticks, labelled with their month where they do not overlap
+        assert_eq!(
+            axis.labels,
+            "2000-01 2009-01 2016-01 2023-01 2029-01 2036-01 2043-01  2050-01 \
+             2057-01 2064-01 2071-01 2077-01 2084-01 2091-01 2098-01"
+        );
+    }
+}
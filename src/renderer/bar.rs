@@ -0,0 +1,196 @@
+use crate::theme::Glyphs;
+use serde::Deserialize;
+
+// Left-aligned partial blocks from 1/8 to 7/8 of a cell
+const PARTIAL_BLOCKS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+// Bottom-aligned partial blocks from 1/8 to 7/8 of a cell
+const LOWER_BLOCKS: [char; 7] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇'];
+const ASCII_FILLED: char = '#';
+const ASCII_EMPTY: char = '-';
+
+/// How a horizontal bar fills its cells.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BarMode {
+    /// Whole cells plus one partial block, for 8 steps per cell
+    #[default]
+    Smooth,
+    /// Whole cells only, using the theme's filled and empty glyphs
+    Block,
+    /// Whole cells drawn with `#` and `-` for terminals without Unicode
+    Ascii,
+}
+
+impl BarMode {
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "smooth" => Some(BarMode::Smooth),
+            "block" => Some(BarMode::Block),
+            "ascii" => Some(BarMode::Ascii),
+            _ => None,
+        }
+    }
+}
+
+/// Builds a bar exactly `width` cells wide, filled to `ratio` (0.0 to 1.0).
+#[must_use]
+pub fn build_bar(width: usize, ratio: f64, glyphs: &Glyphs) -> String {
+    let (filled, empty) = fill_glyphs(glyphs);
+    let (full_cells, partial, empty_cells) = split_cells(width, ratio, glyphs.bar, &PARTIAL_BLOCKS);
+    let mut bar = String::with_capacity(width * filled.len_utf8());
+    bar.extend(std::iter::repeat_n(filled, full_cells));
+    bar.extend(partial);
+    bar.extend(std::iter::repeat_n(empty, empty_cells));
+    bar
+}
+
+/// Builds a vertical bar exactly `height` cells tall, listed top to bottom
+/// and filled from the bottom up to `ratio` (0.0 to 1.0).
+#[must_use]
+pub fn build_column(height: usize, ratio: f64, glyphs: &Glyphs) -> Vec<char> {
+    let (filled, empty) = fill_glyphs(glyphs);
+    let (full_cells, partial, empty_cells) = split_cells(height, ratio, glyphs.bar, &LOWER_BLOCKS);
+    let mut column = Vec::with_capacity(height);
+    column.extend(std::iter::repeat_n(empty, empty_cells));
+    column.extend(partial);
+    column.extend(std::iter::repeat_n(filled, full_cells));
+    column
+}
+
+fn fill_glyphs(glyphs: &Glyphs) -> (char, char) {
+    match glyphs.bar {
+        BarMode::Ascii => (ASCII_FILLED, ASCII_EMPTY),
+        BarMode::Smooth | BarMode::Block => (glyphs.filled, glyphs.empty),
+    }
+}
+
+// Splits `length` cells into full cells, an optional partial block from
+// `partials` and empty cells, adding up to exactly `length`
+#[allow(clippy::cast_sign_loss)]
+#[allow(clippy::cast_precision_loss)]
+#[allow(clippy::cast_possible_truncation)]
+fn split_cells(
+    length: usize,
+    ratio: f64,
+    mode: BarMode,
+    partials: &[char; 7],
+) -> (usize, Option<char>, usize) {
+    let ratio = ratio.clamp(0.0, 1.0);
+    let (full_cells, partial) = match mode {
+        BarMode::Smooth => {
+            let eighths = (length as f64 * 8.0 * ratio).round() as usize;
+            let partial = partials.get((eighths % 8).wrapping_sub(1)).copied();
+            (eighths / 8, partial)
+        }
+        BarMode::Block | BarMode::Ascii => ((length as f64 * ratio).round() as usize, None),
+    };
+    let empty_cells = length - full_cells - usize::from(partial.is_some());
+    (full_cells, partial, empty_cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Style, Theme};
+
+    fn glyphs(bar: BarMode) -> Glyphs {
+        Glyphs {
+            bar,
+            ..Theme::for_style(Style::Default).glyphs
+        }
+    }
+
+    #[test]
+    fn test_build_bar_smooth() {
+        let test_cases = [
+            (0.0, "░░░░░░░░░░"),
+            (0.0125, "▏░░░░░░░░░"),
+            (0.05, "▌░░░░░░░░░"),
+            (0.0875, "▉░░░░░░░░░"),
+            (0.1, "█░░░░░░░░░"),
+            (0.4375, "████▍░░░░░"),
+            (0.5, "█████░░░░░"),
+            (0.99, "█████████▉"),
+            (1.0, "██████████"),
+            (1.5, "██████████"),
+        ];
+        for (ratio, expected) in test_cases {
+            assert_eq!(
+                build_bar(10, ratio, &glyphs(BarMode::Smooth)),
+                expected,
+                "ratio: {ratio}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_bar_block() {
+        let test_cases = [
+            (0.0, "░░░░░░░░░░"),
+            (0.04, "░░░░░░░░░░"),
+            (0.05, "█░░░░░░░░░"),
+            (0.5, "█████░░░░░"),
+            (1.0, "██████████"),
+        ];
+        for (ratio, expected) in test_cases {
+            assert_eq!(
+                build_bar(10, ratio, &glyphs(BarMode::Block)),
+                expected,
+                "ratio: {ratio}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_bar_ascii() {
+        let test_cases = [
+            (0.0, "----------"),
+            (0.44, "####------"),
+            (0.45, "#####-----"),
+            (1.0, "##########"),
+        ];
+        for (ratio, expected) in test_cases {
+            assert_eq!(
+                build_bar(10, ratio, &glyphs(BarMode::Ascii)),
+                expected,
+                "ratio: {ratio}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_bar_width() {
+        for width in [0, 1, 7, 80] {
+            for step in 0..=100 {
+                let ratio = f64::from(step) / 100.0;
+                let bar = build_bar(width, ratio, &glyphs(BarMode::Smooth));
+                assert_eq!(bar.chars().count(), width, "width: {width}, ratio: {ratio}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_build_column() {
+        let test_cases = [
+            (0.0, BarMode::Smooth, "░░░░"),
+            (0.03125, BarMode::Smooth, "░░░▁"),
+            (0.4375, BarMode::Smooth, "░░▆█"),
+            (1.0, BarMode::Smooth, "████"),
+            (0.4375, BarMode::Block, "░░██"),
+            (0.5, BarMode::Ascii, "--##"),
+        ];
+        for (ratio, bar, expected) in test_cases {
+            let column: String = build_column(4, ratio, &glyphs(bar)).into_iter().collect();
+            assert_eq!(column, expected, "ratio: {ratio}, {bar:?}");
+        }
+    }
+
+    #[test]
+    fn test_bar_mode_from_name() {
+        assert_eq!(BarMode::from_name("Smooth"), Some(BarMode::Smooth));
+        assert_eq!(BarMode::from_name("block"), Some(BarMode::Block));
+        assert_eq!(BarMode::from_name("ascii"), Some(BarMode::Ascii));
+        assert_eq!(BarMode::from_name("round"), None);
+    }
+}
@@ -0,0 +1,258 @@
+use crate::{
+    renderer::{build_bar, StyledRenderer},
+    Progress, Theme,
+};
+use anyhow::Result;
+use chrono::Duration;
+use std::io::Write;
+
+// Rows of every glyph in the block font
+const FONT_HEIGHT: usize = 5;
+// A font pixel is two cells wide so that scaled glyphs keep a square look
+const PIXEL_WIDTH: usize = 2;
+// Blank pixel columns between two glyphs
+const GLYPH_GAP: usize = 1;
+// Rows used around the digits: title, blank, blank, bar, information
+const CHROME_ROWS: usize = 5;
+const PIXEL_ON: char = '#';
+const CH_SPACE: char = ' ';
+
+pub struct BigClockRenderer {
+    title: Option<String>,
+    progress: Progress,
+    theme: Theme,
+}
+
+impl StyledRenderer for BigClockRenderer {
+    fn new(title: Option<String>, progress: Progress, theme: Theme) -> Self {
+        BigClockRenderer {
+            title,
+            progress,
+            theme,
+        }
+    }
+
+    fn render_content<W: Write>(&self, w: &mut W) -> Result<u16> {
+        let width = Self::terminal_width();
+        let height = Self::terminal_height();
+        let palette = self.theme.palette;
+        Self::render_background(w, palette.background)?;
+
+        let clock = self.build_clock_text();
+        let scale = Self::scale_for(&clock, width, height);
+        let digits = if scale == 0 {
+            // Too small for the block font: fall back to a single text line
+            vec![format!("{} remaining", self.progress.format_remaining())]
+        } else {
+            self.build_digits(&clock, scale)
+        };
+
+        let content_height = digits.len() + CHROME_ROWS;
+        let top = height.saturating_sub(content_height) / 2;
+        let mut row = 0;
+        for _ in 0..top {
+            row = Self::render_empty_line(w, row)?;
+        }
+        if let Some(title) = &self.title {
+            row = Self::render_colored_line(w, &Self::center(title, width), palette.text, row)?;
+        }
+        row = Self::render_empty_line(w, row)?;
+        for line in &digits {
+            let line = Self::center(line, width);
+            row = Self::render_colored_line(w, &line, self.theme.remaining_color(), row)?;
+        }
+        row = Self::render_empty_line(w, row)?;
+        let bar = build_bar(
+            Self::bar_width(width),
+            self.progress.precise_ratio(),
+            &self.theme.glyphs,
+        );
+        row =
+            Self::render_colored_line(w, &Self::center(&bar, width), self.theme.bar_color(), row)?;
+        let information = self.build_information();
+        row = Self::render_colored_line(w, &Self::center(&information, width), palette.text, row)?;
+        Ok(row)
+    }
+}
+
+impl BigClockRenderer {
+    fn build_clock_text(&self) -> String {
+        format_clock(self.progress.remaining)
+    }
+
+    fn build_information(&self) -> String {
+        format!(
+            "{} → {}   |   {:.0}%",
+            self.progress.timespan.format_from(),
+            self.progress.timespan.format_to(),
+            self.progress.ratio * 100.0
+        )
+    }
+
+    // Width in cells of `text` drawn at scale 1
+    fn text_width(text: &str) -> usize {
+        let glyphs: Vec<_> = text.chars().filter_map(Self::glyph).collect();
+        let pixels = glyphs.iter().map(|g| g[0].len()).sum::<usize>()
+            + GLYPH_GAP * glyphs.len().saturating_sub(1);
+        pixels * PIXEL_WIDTH
+    }
+
+    /// Largest scale at which `text` and the surrounding rows fit in the
+    /// terminal, or 0 when even scale 1 does not fit.
+    fn scale_for(text: &str, width: usize, height: usize) -> usize {
+        let text_width = Self::text_width(text).max(1);
+        let by_width = width / text_width;
+        let by_height = height.saturating_sub(CHROME_ROWS) / FONT_HEIGHT;
+        by_width.min(by_height)
+    }
+
+    fn build_digits(&self, text: &str, scale: usize) -> Vec<String> {
+        let glyphs: Vec<_> = text.chars().filter_map(Self::glyph).collect();
+        let on = self
+            .theme
+            .glyphs
+            .filled
+            .to_string()
+            .repeat(PIXEL_WIDTH * scale);
+        let off = CH_SPACE.to_string().repeat(PIXEL_WIDTH * scale);
+        let gap = off.repeat(GLYPH_GAP);
+        let mut lines = Vec::with_capacity(FONT_HEIGHT * scale);
+        for font_row in 0..FONT_HEIGHT {
+            let line = glyphs
+                .iter()
+                .map(|glyph| {
+                    glyph[font_row]
+                        .chars()
+                        .map(|pixel| {
+                            if pixel == PIXEL_ON {
+                                on.as_str()
+                            } else {
+                                off.as_str()
+                            }
+                        })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join(&gap);
+            for _ in 0..scale {
+                lines.push(line.clone());
+            }
+        }
+        lines
+    }
+
+    fn bar_width(width: usize) -> usize {
+        (width * 4 / 5).max(1)
+    }
+
+    #[rustfmt::skip]
+    fn glyph(c: char) -> Option<[&'static str; FONT_HEIGHT]> {
+        let glyph = match c {
+            '0' => ["###", "# #", "# #", "# #", "###"],
+            '1' => [" # ", "## ", " # ", " # ", "###"],
+            '2' => ["###", "  #", "###", "#  ", "###"],
+            '3' => ["###", "  #", "###", "  #", "###"],
+            '4' => ["# #", "# #", "###", "  #", "  #"],
+            '5' => ["###", "#  ", "###", "  #", "###"],
+            '6' => ["###", "#  ", "###", "# #", "###"],
+            '7' => ["###", "  #", "  #", "  #", "  #"],
+            '8' => ["###", "# #", "###", "# #", "###"],
+            '9' => ["###", "# #", "###", "  #", "###"],
+            'd' => ["  #", "  #", "###", "# #", "###"],
+            ':' => [" ", "#", " ", "#", " "],
+            ' ' => [" ", " ", " ", " ", " "],
+            _ => return None,
+        };
+        Some(glyph)
+    }
+}
+
+/// Remaining time as a clock: `12:34`, `1:02:03` or `2d 03:04`.
+#[must_use]
+pub fn format_clock(remaining: Duration) -> String {
+    let days = remaining.num_days();
+    let hours = remaining.num_hours() % 24;
+    let minutes = remaining.num_minutes() % 60;
+    let seconds = remaining.num_seconds() % 60;
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}")
+    } else if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Style, Timespan};
+    use chrono::NaiveDateTime;
+
+    fn renderer(remaining: Duration) -> BigClockRenderer {
+        let fmt = "%Y-%m-%d %H:%M:%S";
+        let from = NaiveDateTime::parse_from_str("2025-09-01 00:00:00", fmt).unwrap();
+        let to = NaiveDateTime::parse_from_str("2025-09-04 00:00:00", fmt).unwrap();
+        let timespan = Timespan::new(from, to).unwrap();
+        let progress = timespan.progress(to - remaining);
+        BigClockRenderer::new(None, progress, Theme::for_style(Style::BigClock))
+    }
+
+    #[test]
+    fn test_build_clock_text() {
+        let test_cases = [
+            (Duration::seconds(5), "00:05"),
+            (Duration::minutes(25), "25:00"),
+            (Duration::hours(1) + Duration::seconds(61), "1:01:01"),
+            (Duration::hours(23) + Duration::minutes(59), "23:59:00"),
+            (Duration::days(2) + Duration::minutes(7), "2d 00:07"),
+        ];
+        for (remaining, expected) in test_cases {
+            assert_eq!(renderer(remaining).build_clock_text(), expected);
+        }
+    }
+
+    #[test]
+    fn test_build_digits() {
+        let renderer = renderer(Duration::zero());
+        assert_eq!(
+            renderer.build_digits("1:0", 1),
+            vec![
+                "  ██        ██████",
+                "████    ██  ██  ██",
+                "  ██        ██  ██",
+                "  ██    ██  ██  ██",
+                "██████      ██████",
+            ]
+        );
+        let lines = renderer.build_digits("12:34", 3);
+        assert_eq!(lines.len(), FONT_HEIGHT * 3);
+        for line in &lines {
+            assert_eq!(
+                line.chars().count(),
+                BigClockRenderer::text_width("12:34") * 3
+            );
+        }
+    }
+
+    #[test]
+    fn test_scale_for() {
+        // "25:00" is 3+1+3+1+1+1+3+1+3 = 17 pixels, 34 cells wide at scale 1
+        assert_eq!(BigClockRenderer::text_width("25:00"), 34);
+        let test_cases = [
+            (80, 24, 2),
+            (200, 60, 5),
+            (200, 24, 3),
+            (40, 60, 1),
+            (33, 24, 0),
+            (80, 9, 0),
+        ];
+        for (width, height, expected) in test_cases {
+            assert_eq!(
+                BigClockRenderer::scale_for("25:00", width, height),
+                expected,
+                "{width}x{height}"
+            );
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use crossterm::style::{Color, Stylize};
+
+// First code point of the Unicode Braille Patterns block
+const BRAILLE_BASE: u32 = 0x2800;
+// Dot bits of a Braille cell, indexed by [x][y] within its 2x4 grid
+const DOT_BITS: [[u8; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+/// A grid of Braille cells addressed by dot, 2 dots wide and 4 tall per cell.
+///
+/// With terminal cells about twice as tall as they are wide, Braille dots
+/// come out roughly square, so shapes drawn in dot space keep their aspect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrailleCanvas {
+    columns: usize,
+    rows: usize,
+    cells: Vec<u8>,
+}
+
+impl BrailleCanvas {
+    #[must_use]
+    pub fn new(columns: usize, rows: usize) -> Self {
+        BrailleCanvas {
+            columns,
+            rows,
+            cells: vec![0; columns * rows],
+        }
+    }
+
+    #[must_use]
+    pub fn dot_width(&self) -> usize {
+        self.columns * 2
+    }
+
+    #[must_use]
+    pub fn dot_height(&self) -> usize {
+        self.rows * 4
+    }
+
+    /// Sets the dot at (`x`, `y`); dots outside the canvas are ignored.
+    pub fn set(&mut self, x: usize, y: usize) {
+        if x < self.dot_width() && y < self.dot_height() {
+            self.cells[(y / 4) * self.columns + x / 2] |= DOT_BITS[x % 2][y % 4];
+        }
+    }
+
+    #[must_use]
+    pub fn is_set(&self, x: usize, y: usize) -> bool {
+        x < self.dot_width()
+            && y < self.dot_height()
+            && self.cells[(y / 4) * self.columns + x / 2] & DOT_BITS[x % 2][y % 4] != 0
+    }
+
+    /// Whether any dot of the cell at (`column`, `row`) is set.
+    #[must_use]
+    pub fn is_cell_set(&self, column: usize, row: usize) -> bool {
+        self.cells[row * self.columns + column] != 0
+    }
+
+    #[must_use]
+    pub fn cell(&self, column: usize, row: usize) -> char {
+        let bits = self.cells[row * self.columns + column];
+        char::from_u32(BRAILLE_BASE + u32::from(bits)).unwrap_or(' ')
+    }
+
+    /// Cells merged with those of `other`, which must be the same size.
+    #[must_use]
+    pub fn union(&self, other: &BrailleCanvas) -> BrailleCanvas {
+        BrailleCanvas {
+            columns: self.columns,
+            rows: self.rows,
+            cells: self
+                .cells
+                .iter()
+                .zip(&other.cells)
+                .map(|(a, b)| a | b)
+                .collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn lines(&self) -> Vec<String> {
+        (0..self.rows)
+            .map(|row| {
+                (0..self.columns)
+                    .map(|column| self.cell(column, row))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// A character and the colour it is drawn in.
+pub type ColoredCell = (char, Color);
+
+/// Merges same-sized `layers` into coloured cells. A cell takes the colour of
+/// the first layer with a dot in it, or `blank` when none has one.
+#[must_use]
+pub fn colored_cells(layers: &[(&BrailleCanvas, Color)], blank: Color) -> Vec<Vec<ColoredCell>> {
+    let Some((first, _)) = layers.first() else {
+        return Vec::new();
+    };
+    let merged = layers.iter().fold(
+        BrailleCanvas::new(first.columns, first.rows),
+        |merged, (layer, _)| merged.union(layer),
+    );
+    (0..first.rows)
+        .map(|row| {
+            (0..first.columns)
+                .map(|column| {
+                    let color = layers
+                        .iter()
+                        .find(|(layer, _)| layer.is_cell_set(column, row))
+                        .map_or(blank, |(_, color)| *color);
+                    (merged.cell(column, row), color)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Joins cells into one line, switching colour only where it changes.
+#[must_use]
+pub fn style_cells(cells: &[ColoredCell]) -> String {
+    let mut line = String::new();
+    let mut start = 0;
+    while start < cells.len() {
+        let color = cells[start].1;
+        let end = cells[start..]
+            .iter()
+            .position(|(_, c)| *c != color)
+            .map_or(cells.len(), |i| start + i);
+        let segment: String = cells[start..end].iter().map(|(ch, _)| *ch).collect();
+        line.push_str(&segment.with(color).to_string());
+        start = end;
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set() {
+        let mut canvas = BrailleCanvas::new(2, 1);
+        assert_eq!(canvas.lines(), vec!["\u{2800}\u{2800}"]);
+        canvas.set(0, 0);
+        canvas.set(1, 3);
+        canvas.set(2, 1);
+        canvas.set(4, 0);
+        canvas.set(0, 4);
+        assert_eq!(canvas.lines(), vec!["⢁⠂"]);
+        assert!(canvas.is_set(1, 3));
+        assert!(!canvas.is_set(1, 2));
+        assert!(!canvas.is_set(4, 0));
+    }
+
+    #[test]
+    fn test_full_cell() {
+        let mut canvas = BrailleCanvas::new(1, 2);
+        for x in 0..2 {
+            for y in 0..4 {
+                canvas.set(x, y);
+            }
+        }
+        assert_eq!(canvas.lines(), vec!["⣿", "⠀"]);
+        assert!(canvas.is_cell_set(0, 0));
+        assert!(!canvas.is_cell_set(0, 1));
+    }
+
+    #[test]
+    fn test_union() {
+        let mut a = BrailleCanvas::new(1, 1);
+        let mut b = BrailleCanvas::new(1, 1);
+        a.set(0, 0);
+        b.set(1, 0);
+        assert_eq!(a.union(&b).lines(), vec!["⠉"]);
+    }
+
+    #[test]
+    fn test_colored_cells() {
+        let mut a = BrailleCanvas::new(3, 1);
+        let mut b = BrailleCanvas::new(3, 1);
+        a.set(0, 0);
+        b.set(1, 0);
+        b.set(2, 0);
+        let cells = colored_cells(&[(&a, Color::Red), (&b, Color::Blue)], Color::Reset);
+        assert_eq!(
+            cells,
+            vec![vec![
+                ('⠉', Color::Red),
+                ('⠁', Color::Blue),
+                ('\u{2800}', Color::Reset)
+            ]]
+        );
+        assert!(colored_cells(&[], Color::Reset).is_empty());
+    }
+}
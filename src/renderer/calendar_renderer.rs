@@ -0,0 +1,358 @@
+use crate::{renderer::StyledRenderer, Progress, Theme};
+use anyhow::Result;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use crossterm::style::{Color, Stylize};
+use std::io::Write;
+
+// Rows used around the grid: title, blank, header, blank, information
+const CHROME_ROWS: usize = 5;
+// Width of the month or year label in front of each row
+const DAY_LABEL_WIDTH: usize = 4;
+const WEEK_LABEL_WIDTH: usize = 5;
+const WEEKDAY_HEADER: &str = "Mo Tu We Th Fr Sa Su";
+const MAX_ISO_WEEKS: u32 = 53;
+// Shaded glyphs for weekends and holidays, past and future
+const SHADE_PAST: char = '▓';
+const SHADE_FUTURE: char = '▒';
+const CH_SPACE: char = ' ';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DayState {
+    Past,
+    Today,
+    Future,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CalendarCell {
+    /// Outside the timespan
+    Outside,
+    /// A day or week, `off` when it is a weekend or holds a holiday
+    Inside { state: DayState, off: bool },
+}
+
+/// One labelled row of the grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CalendarRow {
+    label: String,
+    cells: Vec<CalendarCell>,
+}
+
+pub struct CalendarRenderer {
+    title: Option<String>,
+    progress: Progress,
+    theme: Theme,
+    holidays: Vec<NaiveDate>,
+}
+
+impl StyledRenderer for CalendarRenderer {
+    fn new(title: Option<String>, progress: Progress, theme: Theme) -> Self {
+        CalendarRenderer {
+            title,
+            progress,
+            theme,
+            holidays: Vec::new(),
+        }
+    }
+
+    fn render_content<W: Write>(&self, w: &mut W) -> Result<u16> {
+        let height = Self::terminal_height();
+        let palette = self.theme.palette;
+        Self::render_background(w, palette.background)?;
+
+        let mut row = 0;
+        if let Some(title) = &self.title {
+            row = Self::render_colored_line(w, title, palette.text, row)?;
+        }
+        row = Self::render_empty_line(w, row)?;
+        // One cell per day while the weeks fit on screen, else one per week
+        let day_rows = self.build_day_rows();
+        let (header, rows, cell_width) = if day_rows.len() <= height.saturating_sub(CHROME_ROWS) {
+            let label = CH_SPACE.to_string().repeat(DAY_LABEL_WIDTH);
+            (format!("{label}{WEEKDAY_HEADER}"), day_rows, 2)
+        } else {
+            (self.build_week_header(), self.build_week_rows(), 1)
+        };
+        row = Self::render_colored_line(w, &header, palette.text, row)?;
+        for calendar_row in &rows {
+            let line = self.style_row(calendar_row, cell_width);
+            row = Self::render_content_line(w, &line, row)?;
+        }
+        row = Self::render_empty_line(w, row)?;
+        let information = self.build_information();
+        row = Self::render_colored_line(w, &information, palette.text, row)?;
+        Ok(row)
+    }
+}
+
+impl CalendarRenderer {
+    /// Shades these dates like weekends.
+    #[must_use]
+    pub fn with_holidays(mut self, holidays: Vec<NaiveDate>) -> Self {
+        self.holidays = holidays;
+        self
+    }
+
+    fn first_day(&self) -> NaiveDate {
+        self.progress.timespan.from.date()
+    }
+
+    // A span ending at midnight does not cover the day it ends on
+    fn last_day(&self) -> NaiveDate {
+        (self.progress.timespan.to - Duration::seconds(1)).date()
+    }
+
+    fn today(&self) -> NaiveDate {
+        self.progress.current_time.date()
+    }
+
+    fn state(&self, first: NaiveDate, last: NaiveDate) -> DayState {
+        let today = self.today();
+        if last < today {
+            DayState::Past
+        } else if first > today {
+            DayState::Future
+        } else {
+            DayState::Today
+        }
+    }
+
+    /// Rows of Monday to Sunday weeks, labelled with the month that starts
+    /// in them.
+    fn build_day_rows(&self) -> Vec<CalendarRow> {
+        let (first, last) = (self.first_day(), self.last_day());
+        let mut monday = first.week(Weekday::Mon).first_day();
+        let mut rows = Vec::new();
+        while monday <= last {
+            let days: Vec<_> = monday.iter_days().take(7).collect();
+            let cells = days
+                .iter()
+                .map(|&day| {
+                    if day < first || day > last {
+                        CalendarCell::Outside
+                    } else {
+                        CalendarCell::Inside {
+                            state: self.state(day, day),
+                            off: day.weekday().num_days_from_monday() >= 5
+                                || self.holidays.contains(&day),
+                        }
+                    }
+                })
+                .collect();
+            let month_start = days
+                .iter()
+                .find(|day| {
+                    (rows.is_empty() && **day == first) || (day.day() == 1 && **day > first)
+                })
+                .filter(|day| **day <= last);
+            let label = month_start.map_or_else(String::new, |day| day.format("%b").to_string());
+            rows.push(CalendarRow {
+                label: format!("{label:<DAY_LABEL_WIDTH$}"),
+                cells,
+            });
+            monday += Duration::weeks(1);
+        }
+        rows
+    }
+
+    /// One row per ISO year, one cell per ISO week.
+    fn build_week_rows(&self) -> Vec<CalendarRow> {
+        let (first, last) = (self.first_day(), self.last_day());
+        (first.iso_week().year()..=last.iso_week().year())
+            .map(|year| {
+                let cells = (1..=MAX_ISO_WEEKS)
+                    .map(|week| {
+                        let Some(monday) = NaiveDate::from_isoywd_opt(year, week, Weekday::Mon)
+                        else {
+                            return CalendarCell::Outside;
+                        };
+                        let sunday = monday + Duration::days(6);
+                        if sunday < first || monday > last {
+                            return CalendarCell::Outside;
+                        }
+                        CalendarCell::Inside {
+                            state: self.state(monday, sunday),
+                            off: self
+                                .holidays
+                                .iter()
+                                .any(|holiday| (monday..=sunday).contains(holiday)),
+                        }
+                    })
+                    .collect();
+                CalendarRow {
+                    label: format!("{year:<WEEK_LABEL_WIDTH$}"),
+                    cells,
+                }
+            })
+            .collect()
+    }
+
+    /// Month names above the ISO week in which each month starts.
+    fn build_week_header(&self) -> String {
+        let year = self.first_day().iso_week().year();
+        let mut header = CH_SPACE.to_string().repeat(WEEK_LABEL_WIDTH);
+        for month in 1..=12 {
+            let Some(date) = NaiveDate::from_ymd_opt(year, month, 1) else {
+                continue;
+            };
+            let week = date.iso_week();
+            let column = if week.year() == year {
+                week.week() as usize - 1
+            } else {
+                0
+            };
+            let position = WEEK_LABEL_WIDTH + column;
+            // Skip a name that would run into the previous one
+            let used = header.chars().count();
+            if position < used + usize::from(used > WEEK_LABEL_WIDTH) {
+                continue;
+            }
+            header.push_str(&CH_SPACE.to_string().repeat(position - used));
+            header.push_str(&date.format("%b").to_string());
+        }
+        header
+    }
+
+    fn cell_glyph(&self, cell: CalendarCell) -> Option<(char, Color)> {
+        let glyphs = self.theme.glyphs;
+        let CalendarCell::Inside { state, off } = cell else {
+            return None;
+        };
+        Some(match (state, off) {
+            (DayState::Past, false) => (glyphs.filled, self.theme.bar_color()),
+            (DayState::Past, true) => (SHADE_PAST, self.theme.bar_color()),
+            (DayState::Today, _) => (glyphs.filled, self.theme.palette.accent),
+            (DayState::Future, false) => (glyphs.empty, self.theme.palette.border),
+            (DayState::Future, true) => (SHADE_FUTURE, self.theme.palette.border),
+        })
+    }
+
+    fn style_row(&self, row: &CalendarRow, cell_width: usize) -> String {
+        let label = row.label.clone().with(self.theme.palette.text).to_string();
+        let separator = if cell_width > 1 { " " } else { "" };
+        let cells = row
+            .cells
+            .iter()
+            .map(|&cell| match self.cell_glyph(cell) {
+                Some((glyph, color)) => {
+                    glyph.to_string().repeat(cell_width).with(color).to_string()
+                }
+                None => CH_SPACE.to_string().repeat(cell_width),
+            })
+            .collect::<Vec<_>>()
+            .join(separator);
+        format!("{label}{cells}")
+    }
+
+    fn build_information(&self) -> String {
+        let space = " ".repeat(3);
+        [
+            format!(
+                "{} → {}",
+                self.progress.timespan.format_from(),
+                self.progress.timespan.format_to()
+            ),
+            format!("{:.0}%", self.progress.ratio * 100.0),
+            format!("{} left", self.progress.format_remaining()),
+        ]
+        .join(format!("{space}|{space}").as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DayState::{Future, Past, Today};
+    use super::*;
+    use crate::{Style, Timespan};
+    use chrono::NaiveDateTime;
+
+    fn renderer(from: &str, to: &str, now: &str) -> CalendarRenderer {
+        let fmt = "%Y-%m-%d %H:%M:%S";
+        let from = NaiveDateTime::parse_from_str(from, fmt).unwrap();
+        let to = NaiveDateTime::parse_from_str(to, fmt).unwrap();
+        let now = NaiveDateTime::parse_from_str(now, fmt).unwrap();
+        let timespan = Timespan::new(from, to).unwrap();
+        CalendarRenderer::new(
+            None,
+            timespan.progress(now),
+            Theme::for_style(Style::Calendar),
+        )
+    }
+
+    fn inside(state: DayState, off: bool) -> CalendarCell {
+        CalendarCell::Inside { state, off }
+    }
+
+    #[test]
+    fn test_build_day_rows() {
+        // Wednesday 2025-09-24 to Tuesday 2025-10-07, today Friday 2025-09-26
+        let holiday = NaiveDate::from_ymd_opt(2025, 10, 3).unwrap();
+        let renderer = renderer(
+            "2025-09-24 00:00:00",
+            "2025-10-08 00:00:00",
+            "2025-09-26 12:00:00",
+        )
+        .with_holidays(vec![holiday]);
+        let rows = renderer.build_day_rows();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].label, "Sep ");
+        assert_eq!(rows[1].label, "Oct ");
+        assert_eq!(rows[2].label, "    ");
+        assert_eq!(
+            rows[0].cells,
+            vec![
+                CalendarCell::Outside,
+                CalendarCell::Outside,
+                inside(Past, false),
+                inside(Past, false),
+                inside(Today, false),
+                inside(Future, true),
+                inside(Future, true),
+            ]
+        );
+        assert_eq!(rows[1].cells[4], inside(Future, true));
+        assert_eq!(rows[1].cells[3], inside(Future, false));
+        assert_eq!(rows[2].cells[1], inside(Future, false));
+        assert_eq!(rows[2].cells[2], CalendarCell::Outside);
+    }
+
+    #[test]
+    fn test_build_week_rows() {
+        let holiday = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let renderer = renderer(
+            "2025-06-01 00:00:00",
+            "2026-03-01 00:00:00",
+            "2025-09-03 12:00:00",
+        )
+        .with_holidays(vec![holiday]);
+        let rows = renderer.build_week_rows();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].label, "2025 ");
+        assert_eq!(rows[1].label, "2026 ");
+        // 2025-06-01 is the Sunday of ISO week 22
+        assert_eq!(rows[0].cells[20], CalendarCell::Outside);
+        assert_eq!(rows[0].cells[21], inside(DayState::Past, false));
+        // 2025-09-03 is in ISO week 36
+        assert_eq!(rows[0].cells[35], inside(DayState::Today, false));
+        assert_eq!(rows[0].cells[36], inside(DayState::Future, false));
+        // 2025 has 52 ISO weeks
+        assert_eq!(rows[0].cells[52], CalendarCell::Outside);
+        // 2026-01-01 is in ISO week 1 of 2026
+        assert_eq!(rows[1].cells[0], inside(DayState::Future, true));
+        // 2026-02-28 is in ISO week 9
+        assert_eq!(rows[1].cells[8], inside(DayState::Future, false));
+        assert_eq!(rows[1].cells[9], CalendarCell::Outside);
+    }
+
+    #[test]
+    fn test_build_week_header() {
+        let renderer = renderer(
+            "2025-01-01 00:00:00",
+            "2026-01-01 00:00:00",
+            "2025-01-01 00:00:00",
+        );
+        let header = renderer.build_week_header();
+        assert!(header.starts_with("     Jan Feb"));
+        assert_eq!(header.find("Dec"), Some(WEEK_LABEL_WIDTH + 48));
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{
+    config::CalendarConfig,
+    horizon::Horizon,
+    renderer::{InlineRenderer, Style, StyledRenderer},
+    Progress, Theme,
+};
+use anyhow::Result;
+use crossterm::style::Stylize;
+use std::io::Write;
+
+// Width of the horizon name in front of its dates
+const LABEL_WIDTH: usize = 12;
+// Rows per horizon: label and progress, plus a blank row when there is room
+const ROWS_PER_HORIZON: usize = 2;
+
+pub struct DashboardRenderer {
+    title: Option<String>,
+    progress: Progress,
+    theme: Theme,
+    calendar: CalendarConfig,
+    style: Style,
+}
+
+impl StyledRenderer for DashboardRenderer {
+    fn new(title: Option<String>, progress: Progress, theme: Theme) -> Self {
+        DashboardRenderer {
+            title,
+            progress,
+            theme,
+            calendar: CalendarConfig::default(),
+            style: Style::Default,
+        }
+    }
+
+    fn render_content<W: Write>(&self, w: &mut W) -> Result<u16> {
+        let width = Self::terminal_width();
+        let height = Self::terminal_height();
+        let palette = self.theme.palette;
+        Self::render_background(w, palette.background)?;
+
+        let mut row = 0;
+        let mut used = Horizon::ALL.len() * ROWS_PER_HORIZON;
+        if let Some(title) = &self.title {
+            row = Self::render_colored_line(w, title, palette.text, row)?;
+            row = Self::render_empty_line(w, row)?;
+            used += 2;
+        }
+        let spaced = used + Horizon::ALL.len() <= height;
+        for (label, progress) in self.build_horizons() {
+            let information = Self::build_information(&label, &progress);
+            row = Self::render_colored_line(w, &information, palette.text, row)?;
+            let line = self.build_progress_line(progress, width);
+            row = Self::render_content_line(w, &line, row)?;
+            if spaced {
+                row = Self::render_empty_line(w, row)?;
+            }
+        }
+        Ok(row)
+    }
+}
+
+impl DashboardRenderer {
+    /// Uses these working hours and fiscal year for the horizons.
+    #[must_use]
+    pub fn with_calendar(mut self, calendar: CalendarConfig) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// Draws each horizon as the one-line variant of this style.
+    #[must_use]
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    // Each horizon's label and progress at the current time
+    fn build_horizons(&self) -> Vec<(String, Progress)> {
+        let now = self.progress.current_time;
+        Horizon::ALL
+            .iter()
+            .filter_map(|horizon| {
+                let timespan = horizon.timespan(now, &self.calendar).ok()?;
+                let label = horizon.label(&timespan, &self.calendar);
+                Some((label, timespan.progress(now)))
+            })
+            .collect()
+    }
+
+    fn build_information(label: &str, progress: &Progress) -> String {
+        format!(
+            "{label:<LABEL_WIDTH$}{} → {}",
+            progress.timespan.format_from(),
+            progress.timespan.format_to()
+        )
+    }
+
+    // Bar and figures of one horizon, drawn the way `--inline` draws this
+    // style
+    fn build_progress_line(&self, progress: Progress, width: usize) -> String {
+        InlineRenderer::new(None, progress, self.theme)
+            .with_style(self.style)
+            .build_segments(width)
+            .into_iter()
+            .map(|(content, color)| content.with(color).to_string())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Style, Timespan};
+    use chrono::NaiveDateTime;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_build_horizons() {
+        let fmt = "%Y-%m-%d %H:%M:%S";
+        let now = NaiveDateTime::parse_from_str("2025-09-03 13:00:00", fmt).unwrap();
+        let timespan = Timespan::new(now, now + chrono::Duration::hours(1)).unwrap();
+        let renderer = DashboardRenderer::new(
+            None,
+            timespan.progress(now),
+            Theme::for_style(Style::Default),
+        );
+        let horizons = renderer.build_horizons();
+        let labels: Vec<_> = horizons.iter().map(|(label, _)| label.as_str()).collect();
+        assert_eq!(labels, ["Today", "Week 36", "September", "Q3", "2025"]);
+        let ratios: Vec<_> = horizons.iter().map(|(_, p)| p.ratio).collect();
+        assert_eq!(ratios, [0.5, 0.36, 0.08, 0.7, 0.67]);
+        assert_eq!(
+            DashboardRenderer::build_information(&horizons[0].0, &horizons[0].1),
+            "Today       09:00 → 17:00"
+        );
+        let line = |style| {
+            let renderer = DashboardRenderer::new(None, horizons[0].1, Theme::for_style(style))
+                .with_style(style);
+            let line = renderer.build_progress_line(horizons[0].1, 30);
+            String::from_utf8(strip_ansi(line.as_bytes())).unwrap()
+        };
+        assert_eq!(line(Style::Default), "█████████░░░░░░░░░ 50% 4h left");
+        assert_eq!(line(Style::Retro), "[████████░░░░░░░░] 50% 4H LEFT");
+    }
+
+    // The characters of a styled line without its colour escapes
+    fn strip_ansi(bytes: &[u8]) -> Vec<u8> {
+        let mut plain = Vec::new();
+        let mut escape = false;
+        for &byte in bytes {
+            match byte {
+                0x1B => escape = true,
+                b'm' if escape => escape = false,
+                _ if !escape => plain.push(byte),
+                _ => {}
+            }
+        }
+        plain
+    }
+}
@@ -1,42 +1,66 @@
-use crate::{renderer::StyledRenderer, Progress};
+use crate::{
+    renderer::{build_axis, build_bar, StyledRenderer},
+    Progress, Theme,
+};
 use anyhow::Result;
 use crossterm::{
     cursor::MoveTo,
     queue,
-    style::{Color, PrintStyledContent, Stylize},
+    style::{PrintStyledContent, Stylize},
 };
 use std::io::Write;
 
 pub struct DefaultRenderer {
     title: Option<String>,
     progress: Progress,
+    theme: Theme,
+    axis: bool,
 }
 
 impl StyledRenderer for DefaultRenderer {
-    fn new(title: Option<String>, progress: Progress) -> Self {
-        DefaultRenderer { title, progress }
+    fn new(title: Option<String>, progress: Progress, theme: Theme) -> Self {
+        DefaultRenderer {
+            title,
+            progress,
+            theme,
+            axis: false,
+        }
     }
 
     fn render_content<W: Write>(&self, w: &mut W) -> Result<u16> {
         let width = Self::terminal_width();
+        let text_color = self.theme.palette.text;
+        Self::render_background(w, self.theme.palette.background)?;
         let title = self.build_title();
         let row = if let Some(title) = title {
-            Self::render_content_line(w, &title, 0)?
+            Self::render_colored_line(w, &title, text_color, 0)?
         } else {
             0
         };
         let infromation = self.build_information();
-        let row = Self::render_content_line(w, &infromation, row)?;
+        let row = Self::render_colored_line(w, &infromation, text_color, row)?;
         let row = Self::render_empty_line(w, row)?;
         let row = self.render_bar(w, width, row)?;
+        let row = if self.axis {
+            self.render_axis(w, width, row)?
+        } else {
+            row
+        };
         let row = Self::render_empty_line(w, row)?;
         let remaining = self.build_remaining();
-        let row = Self::render_content_line(w, &remaining, row)?;
+        let row = Self::render_colored_line(w, &remaining, self.theme.remaining_color(), row)?;
         Ok(row)
     }
 }
 
 impl DefaultRenderer {
+    /// Shows a time axis with labels and a now caret under the bar.
+    #[must_use]
+    pub fn with_axis(mut self, axis: bool) -> Self {
+        self.axis = axis;
+        self
+    }
+
     fn build_title(&self) -> Option<String> {
         self.title.clone()
     }
@@ -64,22 +88,38 @@ impl DefaultRenderer {
         queue!(
             w,
             MoveTo(0, row),
-            PrintStyledContent(bar.with(Color::Reset))
+            PrintStyledContent(bar.with(self.theme.bar_color()))
         )?;
         Ok(row + 1)
     }
 
-    #[allow(clippy::cast_sign_loss)]
-    #[allow(clippy::cast_precision_loss)]
-    #[allow(clippy::cast_possible_truncation)]
+    fn render_axis<W: Write>(&self, w: &mut W, width: usize, row: u16) -> Result<u16> {
+        let axis = build_axis(
+            &self.progress.timespan,
+            self.progress.current_time,
+            width,
+            &self.theme.glyphs,
+        );
+        let border = self.theme.palette.border;
+        // Split the line around the caret to draw it in its own colour
+        let line: Vec<char> = axis.line.chars().collect();
+        let caret = axis.caret.unwrap_or(line.len());
+        let after = (caret + 1).min(line.len());
+        let before: String = line[..caret].iter().collect();
+        let caret: String = line[caret..after].iter().collect();
+        let after: String = line[after..].iter().collect();
+        queue!(
+            w,
+            MoveTo(0, row),
+            PrintStyledContent(before.with(border)),
+            PrintStyledContent(caret.with(self.theme.remaining_color())),
+            PrintStyledContent(after.with(border))
+        )?;
+        Self::render_colored_line(w, &axis.labels, self.theme.palette.text, row + 1)
+    }
+
     fn build_bar(&self, width: usize) -> String {
-        if self.progress.is_complete() {
-            format!("{}{}", "█".repeat(width), "░".repeat(0))
-        } else {
-            let filled_length = (width as f64 * self.progress.ratio).round() as usize;
-            let empty_length = width - filled_length;
-            format!("{}{}", "█".repeat(filled_length), "░".repeat(empty_length))
-        }
+        build_bar(width, self.progress.precise_ratio(), &self.theme.glyphs)
     }
 
     fn build_remaining(&self) -> String {
@@ -95,7 +135,7 @@ impl DefaultRenderer {
 mod tests {
     use chrono::DateTime;
 
-    use crate::{progress, Timespan};
+    use crate::{progress, Style, Timespan};
 
     use super::*;
 
@@ -104,6 +144,7 @@ mod tests {
         let test_cases = vec![
             (0, 100, 0, "░░░░░░░░░░░░░░░░░░░░"),
             (0, 100, 50, "██████████░░░░░░░░░░"),
+            (0, 160, 3, "▍░░░░░░░░░░░░░░░░░░░"),
             (0, 100, 100, "████████████████████"),
         ];
         for (start, end, current, expected) in test_cases {
@@ -116,7 +157,11 @@ mod tests {
                 timespan,
                 DateTime::from_timestamp(current, 0).unwrap().naive_utc(),
             );
-            let renderer = DefaultRenderer::new(Some(String::from("Just Do It!")), progress);
+            let renderer = DefaultRenderer::new(
+                Some(String::from("Just Do It!")),
+                progress,
+                Theme::for_style(Style::Default),
+            );
             let bar = renderer.build_bar(20);
             assert_eq!(bar, expected);
         }
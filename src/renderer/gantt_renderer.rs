@@ -0,0 +1,254 @@
+use crate::{
+    renderer::{
+        axis::{build_axis, position},
+        braille::{style_cells, ColoredCell},
+        StyledRenderer,
+    },
+    timer::Timer,
+    Progress, Theme, Thresholds, Timespan,
+};
+use anyhow::Result;
+use crossterm::style::Stylize;
+use std::io::Write;
+
+// Widest share of the screen given to the names
+const MAX_LABEL_SHARE: usize = 4;
+// Rows under the chart: axis line and its labels
+const AXIS_ROWS: usize = 2;
+
+pub struct GanttRenderer {
+    title: Option<String>,
+    progress: Progress,
+    theme: Theme,
+    timers: Vec<Timer>,
+    thresholds: Option<Thresholds>,
+}
+
+impl StyledRenderer for GanttRenderer {
+    fn new(title: Option<String>, progress: Progress, theme: Theme) -> Self {
+        GanttRenderer {
+            title,
+            progress,
+            theme,
+            timers: Vec::new(),
+            thresholds: None,
+        }
+    }
+
+    fn render_content<W: Write>(&self, w: &mut W) -> Result<u16> {
+        let width = Self::terminal_width();
+        let mut height = Self::terminal_height();
+        let palette = self.theme.palette;
+        Self::render_background(w, palette.background)?;
+
+        let mut row = 0;
+        if let Some(title) = &self.title {
+            row = Self::render_colored_line(w, title, palette.text, row)?;
+            row = Self::render_empty_line(w, row)?;
+            height = height.saturating_sub(2);
+        }
+        let timers = self.timers();
+        let range = Self::range(&timers);
+        let label_width = Self::label_width(&timers, width);
+        let chart_width = width.saturating_sub(label_width + 1);
+        let rows = height.saturating_sub(AXIS_ROWS).max(1);
+        let visible = Self::visible_rows(timers.len(), rows);
+        for timer in &timers[..visible] {
+            let label: String = timer.name.chars().take(label_width).collect();
+            let label = format!("{label:<label_width$} ").with(palette.text);
+            let cells = self.build_row(timer, &range, chart_width);
+            let line = format!("{label}{}", style_cells(&cells));
+            row = Self::render_content_line(w, &line, row)?;
+        }
+        if visible < timers.len() {
+            let more = format!("+{} more", timers.len() - visible);
+            row = Self::render_colored_line(w, &more, palette.border, row)?;
+        }
+
+        let axis = build_axis(
+            &range,
+            self.progress.current_time,
+            chart_width,
+            &self.theme.glyphs,
+        );
+        let indent = " ".repeat(label_width + 1);
+        let line = format!("{indent}{}", axis.line);
+        row = Self::render_colored_line(w, &line, palette.border, row)?;
+        let labels = format!("{indent}{}", axis.labels);
+        row = Self::render_colored_line(w, &labels, palette.text, row)?;
+        Ok(row)
+    }
+}
+
+impl GanttRenderer {
+    /// Draws these timers as rows, in the given order.
+    #[must_use]
+    pub fn with_timers(mut self, timers: Vec<Timer>) -> Self {
+        self.timers = timers;
+        self
+    }
+
+    /// Colours each row by its own remaining time.
+    #[must_use]
+    pub fn with_thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = Some(thresholds);
+        self
+    }
+
+    // The timers, or the main timespan alone when none were given
+    fn timers(&self) -> Vec<Timer> {
+        if self.timers.is_empty() {
+            let name = self.title.clone().unwrap_or_default();
+            vec![Timer::new(&name, self.progress.timespan)]
+        } else {
+            self.timers.clone()
+        }
+    }
+
+    // From the earliest start to the latest end
+    fn range(timers: &[Timer]) -> Timespan {
+        let from = timers.iter().map(|t| t.timespan.from).min();
+        let to = timers.iter().map(|t| t.timespan.to).max();
+        match (from, to) {
+            (Some(from), Some(to)) => Timespan::new(from, to).unwrap_or(timers[0].timespan),
+            _ => timers[0].timespan,
+        }
+    }
+
+    // Timers drawn in `rows`, keeping the last row for a count of the rest
+    // when they do not all fit
+    fn visible_rows(count: usize, rows: usize) -> usize {
+        if count > rows {
+            rows.saturating_sub(1)
+        } else {
+            count
+        }
+    }
+
+    fn label_width(timers: &[Timer], width: usize) -> usize {
+        let longest = timers
+            .iter()
+            .map(|t| t.name.chars().count())
+            .max()
+            .unwrap_or(0);
+        longest.min(width / MAX_LABEL_SHARE)
+    }
+
+    // One timer on the shared axis: elapsed cells filled, remaining cells
+    // shaded, and the now line across every row
+    fn build_row(&self, timer: &Timer, range: &Timespan, width: usize) -> Vec<ColoredCell> {
+        let now = self.progress.current_time;
+        let palette = self.theme.palette;
+        let glyphs = self.theme.glyphs;
+        let progress = timer.timespan.progress(now);
+        let color = self
+            .thresholds
+            .map_or(self.theme, |t| {
+                self.theme.with_urgency(t.urgency(&progress))
+            })
+            .bar_color();
+        let start = position(range, timer.timespan.from, width);
+        let end = position(range, timer.timespan.to, width);
+        let now_cell = (range.from..=range.to)
+            .contains(&now)
+            .then(|| position(range, now, width));
+        let elapsed_until = if progress.is_complete() {
+            end + 1
+        } else if now <= timer.timespan.from {
+            start
+        } else {
+            now_cell.unwrap_or(start)
+        };
+        (0..width)
+            .map(|cell| {
+                if Some(cell) == now_cell {
+                    (glyphs.vertical, palette.accent)
+                } else if !(start..=end).contains(&cell) {
+                    (' ', palette.text)
+                } else if cell < elapsed_until {
+                    (glyphs.filled, color)
+                } else {
+                    (glyphs.empty, color)
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Style;
+    use chrono::NaiveDateTime;
+
+    fn time(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn timer(name: &str, from: &str, to: &str) -> Timer {
+        Timer::new(name, Timespan::new(time(from), time(to)).unwrap())
+    }
+
+    fn renderer(now: &str, timers: Vec<Timer>) -> GanttRenderer {
+        let now = time(now);
+        let timespan = Timespan::new(now, now + chrono::Duration::hours(1)).unwrap();
+        GanttRenderer::new(
+            None,
+            timespan.progress(now),
+            Theme::for_style(Style::Default),
+        )
+        .with_timers(timers)
+    }
+
+    fn row_text(renderer: &GanttRenderer, timer: &Timer, range: &Timespan, width: usize) -> String {
+        renderer
+            .build_row(timer, range, width)
+            .iter()
+            .map(|(c, _)| *c)
+            .collect()
+    }
+
+    #[test]
+    fn test_build_row() {
+        let timers = vec![
+            timer("Standup", "2025-09-03 09:00", "2025-09-03 10:00"),
+            timer("Deploy", "2025-09-03 10:00", "2025-09-03 15:00"),
+            timer("Review", "2025-09-03 15:00", "2025-09-03 19:00"),
+        ];
+        let renderer = renderer("2025-09-03 11:00", timers.clone());
+        let range = GanttRenderer::range(&timers);
+        assert_eq!(range.from, time("2025-09-03 09:00"));
+        assert_eq!(range.to, time("2025-09-03 19:00"));
+        let rows: Vec<_> = timers
+            .iter()
+            .map(|t| row_text(&renderer, t, &range, 11))
+            .collect();
+        assert_eq!(rows, ["██│        ", " █│░░░░    ", "  │   ░░░░░"]);
+    }
+
+    #[test]
+    fn test_label_width() {
+        let timers = vec![
+            timer("Standup", "2025-09-03 09:00", "2025-09-03 10:00"),
+            timer("Deploy window", "2025-09-03 10:00", "2025-09-03 15:00"),
+        ];
+        assert_eq!(GanttRenderer::label_width(&timers, 80), 13);
+        assert_eq!(GanttRenderer::label_width(&timers, 40), 10);
+    }
+
+    #[test]
+    fn test_visible_rows() {
+        assert_eq!(GanttRenderer::visible_rows(3, 5), 3);
+        assert_eq!(GanttRenderer::visible_rows(5, 5), 5);
+        assert_eq!(GanttRenderer::visible_rows(6, 5), 4);
+        assert_eq!(GanttRenderer::visible_rows(6, 1), 0);
+    }
+
+    #[test]
+    fn test_timers_without_timers() {
+        let renderer = renderer("2025-09-03 11:00", Vec::new());
+        let timers = renderer.timers();
+        assert_eq!(timers.len(), 1);
+        assert_eq!(timers[0].timespan, renderer.progress.timespan);
+    }
+}
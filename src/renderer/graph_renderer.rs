@@ -0,0 +1,254 @@
+use crate::{
+    renderer::{
+        braille::{colored_cells, style_cells, BrailleCanvas},
+        StyledRenderer,
+    },
+    Progress, Theme,
+};
+use anyhow::Result;
+use std::io::Write;
+
+// Blank cells on each side of the graph
+const MARGIN: usize = 2;
+// Rows used besides the line chart: title, blank, bar, ticks, labels
+const CHROME_ROWS: usize = 5;
+// The line chart does not need the full height of large terminals
+const MAX_CHART_ROWS: usize = 12;
+// Tick marks split the bar into tenths, with longer marks at each half
+const TICKS: usize = 10;
+const MAJOR_TICK_EVERY: usize = 5;
+const CH_SPACE: char = ' ';
+
+pub struct GraphRenderer {
+    title: Option<String>,
+    progress: Progress,
+    theme: Theme,
+    ideal_line: bool,
+}
+
+impl StyledRenderer for GraphRenderer {
+    fn new(title: Option<String>, progress: Progress, theme: Theme) -> Self {
+        GraphRenderer {
+            title,
+            progress,
+            theme,
+            ideal_line: false,
+        }
+    }
+
+    fn render_content<W: Write>(&self, w: &mut W) -> Result<u16> {
+        let width = Self::terminal_width();
+        let height = Self::terminal_height();
+        let palette = self.theme.palette;
+        Self::render_background(w, palette.background)?;
+
+        let columns = width.saturating_sub(MARGIN * 2).max(1);
+        let margin = CH_SPACE.to_string().repeat(MARGIN);
+        let ratio = self.progress.precise_ratio();
+        let border = palette.border;
+
+        let mut row = 0;
+        if let Some(title) = &self.title {
+            row = Self::render_colored_line(w, &format!("{margin}{title}"), palette.text, row)?;
+        }
+        row = Self::render_empty_line(w, row)?;
+        let chart_rows = Self::chart_rows(height);
+        if self.ideal_line && chart_rows > 0 {
+            let (past, ahead) = Self::build_ideal_line(columns, chart_rows, ratio);
+            let layers = [(&past, self.theme.bar_color()), (&ahead, border)];
+            for cells in colored_cells(&layers, border) {
+                let line = format!("{margin}{}", style_cells(&cells));
+                row = Self::render_content_line(w, &line, row)?;
+            }
+        }
+        let (filled, track) = Self::build_bar(columns, ratio);
+        for cells in colored_cells(
+            &[(&filled, self.theme.bar_color()), (&track, border)],
+            border,
+        ) {
+            let line = format!("{margin}{}", style_cells(&cells));
+            row = Self::render_content_line(w, &line, row)?;
+        }
+        for line in Self::build_ticks(columns).lines() {
+            row = Self::render_colored_line(w, &format!("{margin}{line}"), border, row)?;
+        }
+        let labels = format!("{margin}{}", self.build_labels(columns));
+        row = Self::render_colored_line(w, &labels, self.theme.remaining_color(), row)?;
+        Ok(row)
+    }
+}
+
+impl GraphRenderer {
+    /// Draws the ideal line above the bar, solid up to now.
+    #[must_use]
+    pub fn with_ideal_line(mut self, ideal_line: bool) -> Self {
+        self.ideal_line = ideal_line;
+        self
+    }
+
+    fn chart_rows(height: usize) -> usize {
+        height.saturating_sub(CHROME_ROWS).min(MAX_CHART_ROWS)
+    }
+
+    /// A bar one cell tall with eight steps per cell: two dot columns, each
+    /// filling from the bottom up. The empty part is a track along the bottom.
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_possible_truncation)]
+    fn build_bar(columns: usize, ratio: f64) -> (BrailleCanvas, BrailleCanvas) {
+        let mut filled = BrailleCanvas::new(columns, 1);
+        let mut track = BrailleCanvas::new(columns, 1);
+        let dot_height = filled.dot_height();
+        let steps = (filled.dot_width() as f64 * dot_height as f64 * ratio.clamp(0.0, 1.0)).round()
+            as usize;
+        let (full, partial) = (steps / dot_height, steps % dot_height);
+        for x in 0..filled.dot_width() {
+            let height = match x.cmp(&full) {
+                std::cmp::Ordering::Less => dot_height,
+                std::cmp::Ordering::Equal => partial,
+                std::cmp::Ordering::Greater => 0,
+            };
+            for y in dot_height - height..dot_height {
+                filled.set(x, y);
+            }
+            if height == 0 {
+                track.set(x, dot_height - 1);
+            }
+        }
+        (filled, track)
+    }
+
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_possible_truncation)]
+    fn build_ticks(columns: usize) -> BrailleCanvas {
+        let mut ticks = BrailleCanvas::new(columns, 1);
+        let last = ticks.dot_width().saturating_sub(1);
+        for tick in 0..=TICKS {
+            let x = (last as f64 * tick as f64 / TICKS as f64).round() as usize;
+            let length = if tick % MAJOR_TICK_EVERY == 0 { 4 } else { 2 };
+            for y in 0..length {
+                ticks.set(x, y);
+            }
+        }
+        ticks
+    }
+
+    /// The ideal line, solid up to now and dotted from now to the end, so
+    /// that the end of the solid part marks now. Progress only follows the
+    /// clock, so there is no separate elapsed series to plot against it.
+    /// Time runs left to right and completion bottom to top.
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_possible_truncation)]
+    fn build_ideal_line(columns: usize, rows: usize, ratio: f64) -> (BrailleCanvas, BrailleCanvas) {
+        let mut past = BrailleCanvas::new(columns, rows);
+        let mut ahead = BrailleCanvas::new(columns, rows);
+        let last_x = past.dot_width().saturating_sub(1).max(1) as f64;
+        let last_y = past.dot_height().saturating_sub(1) as f64;
+        let y_at = |x: usize| ((1.0 - x as f64 / last_x) * last_y).round() as usize;
+        let mut previous = y_at(0);
+        for x in 0..past.dot_width() {
+            let y = y_at(x);
+            // Fill the vertical gap so steep lines stay connected
+            let span = y.min(previous)..=y.max(previous);
+            if x as f64 / last_x <= ratio {
+                for y in span {
+                    past.set(x, y);
+                }
+            } else if x % 2 == 0 {
+                for y in span {
+                    ahead.set(x, y);
+                }
+            }
+            previous = y;
+        }
+        (past, ahead)
+    }
+
+    fn build_labels(&self, columns: usize) -> String {
+        let from = self.progress.timespan.format_from();
+        let to = self.progress.timespan.format_to();
+        let status = if self.progress.is_complete() {
+            "Completed".to_string()
+        } else {
+            format!(
+                "{:.1}%  {} left",
+                self.progress.precise_ratio() * 100.0,
+                self.progress.format_remaining()
+            )
+        };
+        let used = from.chars().count() + status.chars().count() + to.chars().count();
+        let gap = columns.saturating_sub(used);
+        format!(
+            "{from}{}{status}{}{to}",
+            CH_SPACE.to_string().repeat(gap / 2),
+            CH_SPACE.to_string().repeat(gap - gap / 2)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Style, Timespan};
+    use chrono::{Duration, NaiveDateTime};
+
+    #[test]
+    fn test_build_bar() {
+        let test_cases = [
+            (0.0, "⣀⣀⣀⣀"),
+            (0.125, "⣇⣀⣀⣀"),
+            (0.25, "⣿⣀⣀⣀"),
+            (0.5, "⣿⣿⣀⣀"),
+            (0.5625, "⣿⣿⣄⣀"),
+            (1.0, "⣿⣿⣿⣿"),
+        ];
+        for (ratio, expected) in test_cases {
+            let (filled, track) = GraphRenderer::build_bar(4, ratio);
+            assert_eq!(filled.union(&track).lines(), vec![expected], "{ratio}");
+        }
+    }
+
+    #[test]
+    fn test_build_ticks() {
+        assert_eq!(GraphRenderer::build_ticks(10).lines(), vec!["⡇⠃⠃⠃⠃⡟⠘⠘⠘⢸"]);
+    }
+
+    #[test]
+    fn test_build_ideal_line() {
+        let (past, ahead) = GraphRenderer::build_ideal_line(4, 2, 0.5);
+        assert!(past.is_set(0, 7));
+        assert!(past.is_set(3, 4));
+        assert!(!past.is_set(4, 3));
+        assert!(ahead.is_set(4, 3));
+        assert!(!ahead.is_set(5, 2));
+        assert!(ahead.is_set(6, 1));
+        assert_eq!(ahead.union(&past).lines(), vec!["⠀⠀⡀⠆", "⣠⠞⠁⠀"]);
+
+        let (_, ahead) = GraphRenderer::build_ideal_line(4, 2, 1.0);
+        assert_eq!(ahead, BrailleCanvas::new(4, 2));
+    }
+
+    #[test]
+    fn test_chart_rows() {
+        assert_eq!(GraphRenderer::chart_rows(24), MAX_CHART_ROWS);
+        assert_eq!(GraphRenderer::chart_rows(10), 5);
+        assert_eq!(GraphRenderer::chart_rows(4), 0);
+    }
+
+    #[test]
+    fn test_build_labels() {
+        let fmt = "%Y-%m-%d %H:%M:%S";
+        let from = NaiveDateTime::parse_from_str("2025-01-01 00:00:00", fmt).unwrap();
+        let to = NaiveDateTime::parse_from_str("2025-07-01 00:00:00", fmt).unwrap();
+        let timespan = Timespan::new(from, to).unwrap();
+        let progress = timespan.progress(from + Duration::days(1));
+        let renderer = GraphRenderer::new(None, progress, Theme::for_style(Style::Graph));
+        let labels = renderer.build_labels(50);
+        assert_eq!(labels.chars().count(), 50);
+        assert!(labels.starts_with("2025-01-01"));
+        assert!(labels.contains("0.6%  180d left"));
+        assert!(labels.ends_with("2025-07-01"));
+    }
+}
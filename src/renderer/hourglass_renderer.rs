@@ -1,22 +1,10 @@
-use crate::{renderer::StyledRenderer, Progress};
+use crate::{renderer::StyledRenderer, Progress, Theme};
 use anyhow::Result;
 use std::io::Write;
 use std::sync::OnceLock;
 use std::time::Instant;
 
-// Fixed inner width of the hourglass content (between the side borders)
-const INNER_WIDTH: usize = 9;
-// Drawing characters (customize here)
-const CH_BORDER_TL: char = '┏';
-const CH_BORDER_TR: char = '┓';
-const CH_BORDER_BL: char = '┗';
-const CH_BORDER_BR: char = '┛';
-const CH_BORDER_H: char = '━';
-const CH_BORDER_V: char = '┃';
-
-const CH_SAND: char = '█';
-const CH_EMPTY: char = '░';
-
+// Drawing characters (borders, sand and empty cells come from the theme)
 // const CH_FLOW_MAIN: char = '┋';
 const CH_FLOW_MAIN: char = '┊';
 const CH_FLOW_TRAIL: char = '┊';
@@ -26,29 +14,82 @@ const CH_SPACE: char = ' ';
 const SEP_ARROW: &str = "→";
 const INFO_DIVIDER: char = '|';
 
-// Top/bottom reservoir row counts for the hourglass box interior
-// Ensure total movable sand capacity is 60 cells.
-// Top: 6 rows x 9 + joins (5+1) = 54 + 6 = 60
-// Bottom: joins (1+5) + 6 rows x 9 = 6 + 54 = 60 (neck is not counted)
-const TOP_ROWS: usize = 6;
-const BOTTOM_ROWS: usize = 6;
+// Each funnel join narrows the interior by two cells on either side
+const JOIN_STEP: usize = 4;
+// Reservoir rows per funnel join, keeping the proportions of the 9-wide glass
+const ROWS_PER_JOIN: usize = 3;
+// Rows used around the hourglass: title, header and footer
+const CHROME_ROWS: usize = 3;
+
+/// Size of the hourglass, derived from the number of funnel joins.
+///
+/// With `joins` joins the interior is `4 * joins + 1` cells wide and the
+/// funnel narrows by 4 cells per join down to the one-cell neck.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Geometry {
+    joins: usize,
+    reservoir_rows: usize,
+}
+
+impl Geometry {
+    /// Largest hourglass that fits in `width` x `height` cells, and at
+    /// least one join with one reservoir row.
+    fn fit(width: usize, height: usize) -> Self {
+        let rows = height.saturating_sub(CHROME_ROWS);
+        // Borders and neck take 3 rows; each join adds 2 funnel and 2 * 3 reservoir rows
+        let by_height = rows.saturating_sub(3) / (2 + 2 * ROWS_PER_JOIN);
+        let by_width = width.saturating_sub(3) / JOIN_STEP;
+        let joins = by_height.min(by_width).max(1);
+        let reservoir_rows = (ROWS_PER_JOIN * joins)
+            .min(rows.saturating_sub(3 + 2 * joins) / 2)
+            .max(1);
+        Geometry {
+            joins,
+            reservoir_rows,
+        }
+    }
+
+    fn inner_width(self) -> usize {
+        JOIN_STEP * self.joins + 1
+    }
+
+    // Inner widths of the top funnel joins, from the widest down to the neck
+    fn join_widths(self) -> Vec<usize> {
+        (1..=self.joins)
+            .map(|i| self.inner_width() - JOIN_STEP * i)
+            .collect()
+    }
+
+    /// Movable sand cells: one reservoir and its funnel (the neck is not counted).
+    fn capacity(self) -> usize {
+        self.reservoir_rows * self.inner_width() + self.join_widths().iter().sum::<usize>()
+    }
+}
 
 pub struct HourglassRenderer {
     title: Option<String>,
     progress: Progress,
+    theme: Theme,
 }
 
 static START_INSTANT: OnceLock<Instant> = OnceLock::new();
 
 impl StyledRenderer for HourglassRenderer {
-    fn new(title: Option<String>, progress: Progress) -> Self {
-        HourglassRenderer { title, progress }
+    fn new(title: Option<String>, progress: Progress, theme: Theme) -> Self {
+        HourglassRenderer {
+            title,
+            progress,
+            theme,
+        }
     }
 
     fn render_content<W: Write>(&self, w: &mut W) -> Result<u16> {
+        let geometry = Geometry::fit(Self::terminal_width(), Self::terminal_height());
+        let palette = self.theme.palette;
+        Self::render_background(w, palette.background)?;
         let title = self.build_title();
         let mut row = if let Some(title) = title {
-            Self::render_content_line(w, &title, 0)?
+            Self::render_colored_line(w, &title, palette.text, 0)?
         } else {
             0
         };
@@ -78,7 +119,7 @@ impl StyledRenderer for HourglassRenderer {
         };
 
         // Compute base center of hourglass (including left border)
-        let base_center = 1 + (INNER_WIDTH / 2);
+        let base_center = 1 + (geometry.inner_width() / 2);
         let left_pad = anchor_col.saturating_sub(base_center);
         let pad = CH_SPACE.to_string().repeat(left_pad);
 
@@ -86,11 +127,11 @@ impl StyledRenderer for HourglassRenderer {
         let header_left_pad = (left_pad + base_center).saturating_sub(header_divider_col);
         let header_pad = CH_SPACE.to_string().repeat(header_left_pad);
         let header_padded = format!("{}{}", header_pad, header);
-        row = Self::render_content_line(w, &header_padded, row)?;
+        row = Self::render_colored_line(w, &header_padded, palette.text, row)?;
 
         // Render the hourglass box
-        for line in self.build_hourglass() {
-            Self::render_content_line(w, &format!("{}{}", pad, line), row)?;
+        for line in self.build_hourglass(geometry) {
+            Self::render_colored_line(w, &format!("{}{}", pad, line), self.theme.bar_color(), row)?;
             row += 1;
         }
 
@@ -98,7 +139,7 @@ impl StyledRenderer for HourglassRenderer {
         let footer_left_pad = (left_pad + base_center).saturating_sub(footer_divider_col);
         let footer_pad = CH_SPACE.to_string().repeat(footer_left_pad);
         let footer_padded = format!("{}{}", footer_pad, footer);
-        row = Self::render_content_line(w, &footer_padded, row)?;
+        row = Self::render_colored_line(w, &footer_padded, palette.text, row)?;
         Ok(row)
     }
 }
@@ -125,37 +166,36 @@ impl HourglassRenderer {
     }
 
     // Build full box (top border, interior lines, bottom border)
-    // Interior lines = TOP_ROWS + 2 (top joins) + 1 (neck) + 2 (bottom joins) + BOTTOM_ROWS
-    fn build_hourglass(&self) -> Vec<String> {
+    // Interior lines = reservoir rows + joins + 1 (neck) + joins + reservoir rows
+    fn build_hourglass(&self, geometry: Geometry) -> Vec<String> {
+        let inner_width = geometry.inner_width();
         let start = START_INSTANT.get_or_init(Instant::now);
         let elapsed = start.elapsed();
         let flow_active = !self.progress.is_complete();
-
-        // Compute total capacity (movable sand cells) = 60
-        // Done after defining `top_fun_widths` for clarity.
+        let sand = self.theme.glyphs.filled;
+        let empty = self.theme.glyphs.empty;
 
         // Prepare structures
         // Top reservoir (display order: top->bottom)
-        let mut top_res: Vec<Vec<char>> =
-            (0..TOP_ROWS).map(|_| vec![CH_SAND; INNER_WIDTH]).collect();
-        // Top funnel (new appearance): two join lines with inner widths 5 and 1
-        let top_fun_widths = [5usize, 1];
-        let mut top_fun: Vec<Vec<char>> =
-            top_fun_widths.iter().map(|&w| vec![CH_SAND; w]).collect();
+        let mut top_res: Vec<Vec<char>> = (0..geometry.reservoir_rows)
+            .map(|_| vec![sand; inner_width])
+            .collect();
+        // Top funnel: join lines narrowing down to the neck
+        let top_fun_widths = geometry.join_widths();
+        let mut top_fun: Vec<Vec<char>> = top_fun_widths.iter().map(|&w| vec![sand; w]).collect();
 
-        // Compute total capacity (movable sand cells) from top geometry
-        let total_cells = TOP_ROWS * INNER_WIDTH + top_fun_widths.iter().sum::<usize>(); // 54 + 6 = 60
+        // Total capacity (movable sand cells) from the top geometry
+        let total_cells = geometry.capacity();
 
         // Bottom reservoir (display order: top->bottom)
-        let mut bot_res: Vec<Vec<char>> = (0..BOTTOM_ROWS)
-            .map(|_| vec![CH_EMPTY; INNER_WIDTH])
+        let mut bot_res: Vec<Vec<char>> = (0..geometry.reservoir_rows)
+            .map(|_| vec![empty; inner_width])
             .collect();
-        // Bottom funnel (new appearance): two join lines with inner widths 1 and 5
-        let bot_fun_widths = [1usize, 5];
-        let mut bot_fun: Vec<Vec<char>> =
-            bot_fun_widths.iter().map(|&w| vec![CH_EMPTY; w]).collect();
+        // Bottom funnel: join lines widening from the neck
+        let bot_fun_widths: Vec<usize> = top_fun_widths.iter().rev().copied().collect();
+        let mut bot_fun: Vec<Vec<char>> = bot_fun_widths.iter().map(|&w| vec![empty; w]).collect();
         // Neck cell
-        let mut neck = CH_EMPTY;
+        let mut neck = empty;
 
         // Helper: center-out order indices for a given width
         let center_order = |w: usize| -> Vec<usize> {
@@ -181,21 +221,20 @@ impl HourglassRenderer {
         };
 
         // Build bottom fill order (reservoir bottom->top, then funnel bottom->top)
-        // Note: neck is excluded from capacity so total is exactly 60.
+        // Note: neck is excluded from capacity so both halves hold the same sand.
         let mut bottom_coords: Vec<(u8, usize, usize)> = Vec::new();
         // Reservoir rows: bottom-most first
-        for r in (0..BOTTOM_ROWS).rev() {
-            for c in center_order(INNER_WIDTH) {
+        for r in (0..geometry.reservoir_rows).rev() {
+            for c in center_order(inner_width) {
                 bottom_coords.push((0, r, c)); // 0 = bot_res
             }
         }
-        // Funnel rows: bottom-most first (width 5), then (width 1)
+        // Funnel rows: bottom-most (widest) first
         for (i, &w) in bot_fun_widths.iter().enumerate().rev() {
             for c in center_order(w) {
                 bottom_coords.push((1, i, c)); // 1 = bot_fun
             }
         }
-        // total_cells is fixed from top geometry (60)
         let filled = (self.progress.ratio.clamp(0.0, 1.0) * total_cells as f64).round() as usize;
 
         // Apply bottom filled cells according to progress
@@ -204,28 +243,27 @@ impl HourglassRenderer {
                 break;
             }
             match section {
-                0 => bot_res[i][j] = CH_SAND,
-                1 => bot_fun[i][j] = CH_SAND,
+                0 => bot_res[i][j] = sand,
+                1 => bot_fun[i][j] = sand,
                 _ => {}
             }
         }
 
         // At 100% completion, keep the neck visually open (empty)
         if self.progress.is_complete() {
-            neck = CH_EMPTY;
+            neck = empty;
         }
 
         // Build top empty order (reservoir top->down first, then funnel top side)
         let mut top_coords: Vec<(u8, usize, usize)> = Vec::with_capacity(total_cells);
-        // Reservoir rows: top-most first => 0..4
-        for r in 0..TOP_ROWS {
-            for c in center_order(INNER_WIDTH) {
+        // Reservoir rows: top-most first
+        for r in 0..geometry.reservoir_rows {
+            for c in center_order(inner_width) {
                 top_coords.push((4, r, c)); // 4 = top_res
             }
         }
-        // Funnel rows: top side first in new appearance => widths 5, then 1
-        for i in 0..top_fun_widths.len() {
-            let w = top_fun_widths[i];
+        // Funnel rows: top (widest) first
+        for (i, &w) in top_fun_widths.iter().enumerate() {
             for c in center_order(w) {
                 top_coords.push((3, i, c)); // 3 = top_fun
             }
@@ -236,8 +274,8 @@ impl HourglassRenderer {
                 break;
             }
             match section {
-                3 => top_fun[i][j] = CH_EMPTY,
-                _ => top_res[i][j] = CH_EMPTY,
+                3 => top_fun[i][j] = empty,
+                _ => top_res[i][j] = empty,
             }
         }
 
@@ -261,12 +299,12 @@ impl HourglassRenderer {
             let mut active_len = 0usize;
             for (sec, idx) in &path {
                 let occupied = match *sec {
-                    2 => neck_char == CH_SAND,
+                    2 => neck_char == sand,
                     1 => {
                         let w = bot_fun[*idx].len();
-                        bot_fun[*idx][w / 2] == CH_SAND
+                        bot_fun[*idx][w / 2] == sand
                     }
-                    _ => bot_res[*idx][INNER_WIDTH / 2] == CH_SAND,
+                    _ => bot_res[*idx][inner_width / 2] == sand,
                 };
                 if occupied {
                     break;
@@ -289,7 +327,7 @@ impl HourglassRenderer {
                             bot_fun[*idx][mid] = if is_main { main_ch } else { trail_ch };
                         }
                         _ => {
-                            let mid = INNER_WIDTH / 2;
+                            let mid = inner_width / 2;
                             bot_res[*idx][mid] = if is_main { main_ch } else { trail_ch };
                         }
                     }
@@ -298,97 +336,223 @@ impl HourglassRenderer {
         }
 
         // Compose output lines
-        let mut lines: Vec<String> = Vec::with_capacity(20);
-        lines.push(Self::top_border());
+        let mut lines: Vec<String> =
+            Vec::with_capacity(2 * (geometry.reservoir_rows + geometry.joins) + 3);
+        lines.push(self.top_border(inner_width));
         // Top reservoir (display order)
-        for r in 0..TOP_ROWS {
-            lines.push(Self::boxed(top_res[r].iter().collect()));
+        for row in &top_res {
+            lines.push(self.boxed(row.iter().collect()));
         }
-        // Top funnel join lines (widths 5, then 1)
+        // Top funnel join lines, narrowing
         for (i, row) in top_fun.iter().enumerate() {
-            lines.push(Self::join_line_top(row, i));
+            lines.push(self.join_line_top(row, i, inner_width));
         }
         // Neck
-        lines.push(Self::funnel_line(4, neck_char, 1));
-        // Lower funnel join lines (widths 1, then 5)
+        lines.push(self.funnel_line(2 * geometry.joins, neck_char, 1));
+        // Lower funnel join lines, widening
         for (i, row) in bot_fun.iter().enumerate() {
-            lines.push(Self::join_line_bottom(row, i));
+            lines.push(self.join_line_bottom(row, geometry.joins - 1 - i, inner_width));
         }
         // Lower reservoir (display order)
-        for r in 0..BOTTOM_ROWS {
-            lines.push(Self::boxed(bot_res[r].iter().collect()));
+        for row in &bot_res {
+            lines.push(self.boxed(row.iter().collect()));
         }
-        lines.push(Self::bottom_border());
+        lines.push(self.bottom_border(inner_width));
 
         lines
     }
 
     // Draw a boxed line with side borders
-    fn boxed(inner: String) -> String {
-        format!("{}{}{}", CH_BORDER_V, inner, CH_BORDER_V)
+    fn boxed(&self, inner: String) -> String {
+        let vertical = self.theme.glyphs.vertical;
+        format!("{}{}{}", vertical, inner, vertical)
     }
 
     // Upper/lower funnel line with fixed char repeated `width` and left indentation
-    fn funnel_line(indent: usize, ch: char, width: usize) -> String {
-        let inner: String = std::iter::repeat(ch).take(width).collect();
+    fn funnel_line(&self, indent: usize, ch: char, width: usize) -> String {
+        let inner: String = std::iter::repeat_n(ch, width).collect();
+        let vertical = self.theme.glyphs.vertical;
         format!(
             "{}{}{}{}",
             CH_SPACE.to_string().repeat(indent),
-            CH_BORDER_V,
+            vertical,
             inner,
-            CH_BORDER_V
+            vertical
         )
     }
 
     // Join line for the top funnel section using corner connectors
-    // i = 0 => width 5 with indent 0; i = 1 => width 1 with indent 2
-    fn join_line_top(inner: &[char], i: usize) -> String {
+    // Join `level` counts from the widest (indent 0), each one indented by 2
+    fn join_line_top(&self, inner: &[char], level: usize, inner_width: usize) -> String {
+        let g = self.theme.glyphs;
         let w = inner.len();
-        let total = INNER_WIDTH + 2; // full interior width of the hourglass area
-        let left_indent = if i == 0 { 0 } else { 2 };
+        let total = inner_width + 2; // full interior width of the hourglass area
+        let left_indent = 2 * level;
         let used = left_indent + 3 + w + 3; // left + "┗━┓" + inner + "┏━┛"
         let right_pad = total.saturating_sub(used);
         let inner_s: String = inner.iter().collect();
         format!(
-            "{}┗━┓{}┏━┛{}",
+            "{}{}{}{}{}{}{}{}{}",
             CH_SPACE.to_string().repeat(left_indent),
+            g.bottom_left,
+            g.horizontal,
+            g.top_right,
             inner_s,
+            g.top_left,
+            g.horizontal,
+            g.bottom_right,
             CH_SPACE.to_string().repeat(right_pad)
         )
     }
 
     // Join line for the bottom funnel section using corner connectors
-    // i = 0 => width 1 with indent 2; i = 1 => width 5 with indent 0
-    fn join_line_bottom(inner: &[char], i: usize) -> String {
+    // Join `level` counts from the widest (indent 0), each one indented by 2
+    fn join_line_bottom(&self, inner: &[char], level: usize, inner_width: usize) -> String {
+        let g = self.theme.glyphs;
         let w = inner.len();
-        let total = INNER_WIDTH + 2;
-        let left_indent = if i == 0 { 2 } else { 0 };
+        let total = inner_width + 2;
+        let left_indent = 2 * level;
         let used = left_indent + 3 + w + 3; // left + "┏━┛" + inner + "┗━┓"
         let right_pad = total.saturating_sub(used);
         let inner_s: String = inner.iter().collect();
         format!(
-            "{}┏━┛{}┗━┓{}",
+            "{}{}{}{}{}{}{}{}{}",
             CH_SPACE.to_string().repeat(left_indent),
+            g.top_left,
+            g.horizontal,
+            g.bottom_right,
             inner_s,
+            g.bottom_left,
+            g.horizontal,
+            g.top_right,
             CH_SPACE.to_string().repeat(right_pad)
         )
     }
 
-    fn top_border() -> String {
+    fn top_border(&self, inner_width: usize) -> String {
+        let g = self.theme.glyphs;
         format!(
             "{}{}{}",
-            CH_BORDER_TL,
-            CH_BORDER_H.to_string().repeat(INNER_WIDTH),
-            CH_BORDER_TR
+            g.top_left,
+            g.horizontal.to_string().repeat(inner_width),
+            g.top_right
         )
     }
 
-    fn bottom_border() -> String {
+    fn bottom_border(&self, inner_width: usize) -> String {
+        let g = self.theme.glyphs;
         format!(
             "{}{}{}",
-            CH_BORDER_BL,
-            CH_BORDER_H.to_string().repeat(INNER_WIDTH),
-            CH_BORDER_BR
+            g.bottom_left,
+            g.horizontal.to_string().repeat(inner_width),
+            g.bottom_right
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Style, Timespan};
+    use chrono::{Duration, NaiveDateTime};
+
+    fn renderer(elapsed_minutes: i64) -> HourglassRenderer {
+        let fmt = "%Y-%m-%d %H:%M:%S";
+        let from = NaiveDateTime::parse_from_str("2025-09-01 09:00:00", fmt).unwrap();
+        let to = from + Duration::minutes(100);
+        let timespan = Timespan::new(from, to).unwrap();
+        HourglassRenderer::new(
+            None,
+            timespan.progress(from + Duration::minutes(elapsed_minutes)),
+            Theme::for_style(Style::Hourglass),
+        )
+    }
+
+    #[test]
+    fn test_geometry_fit() {
+        let test_cases = [
+            ((80, 24), (2, 6, 60)),
+            ((80, 50), (5, 15, 360)),
+            ((10, 50), (1, 3, 16)),
+            ((80, 12), (1, 2, 11)),
+            ((80, 5), (1, 1, 6)),
+        ];
+        for ((width, height), (joins, reservoir_rows, capacity)) in test_cases {
+            let geometry = Geometry::fit(width, height);
+            assert_eq!(
+                geometry,
+                Geometry {
+                    joins,
+                    reservoir_rows
+                },
+                "{width}x{height}"
+            );
+            assert_eq!(geometry.capacity(), capacity, "{width}x{height}");
+        }
+    }
+
+    #[test]
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_possible_truncation)]
+    fn test_build_hourglass_sizes() {
+        let sand = Theme::for_style(Style::Hourglass).glyphs.filled;
+        let count_sand = |lines: &[String]| -> usize {
+            lines
+                .iter()
+                .map(|line| line.chars().filter(|c| *c == sand).count())
+                .sum()
+        };
+        for joins in 1..=6 {
+            for reservoir_rows in [1, 3 * joins] {
+                let geometry = Geometry {
+                    joins,
+                    reservoir_rows,
+                };
+                let capacity = geometry.capacity();
+                let neck = 1 + reservoir_rows + joins;
+                for elapsed in [0, 25, 50, 99, 100] {
+                    let lines = renderer(elapsed).build_hourglass(geometry);
+                    assert_eq!(lines.len(), 2 * (reservoir_rows + joins) + 3);
+                    for line in &lines {
+                        assert!(line.chars().count() <= geometry.inner_width() + 2);
+                    }
+                    assert_eq!(
+                        lines[0].chars().count(),
+                        geometry.inner_width() + 2,
+                        "top border"
+                    );
+                    let fallen = (elapsed as f64 / 100.0 * capacity as f64).round() as usize;
+                    assert_eq!(
+                        count_sand(&lines[..neck]),
+                        capacity - fallen,
+                        "{geometry:?}"
+                    );
+                    assert_eq!(count_sand(&lines[neck + 1..]), fallen, "{geometry:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_build_hourglass_default_shape() {
+        let lines = renderer(100).build_hourglass(Geometry {
+            joins: 2,
+            reservoir_rows: 1,
+        });
+        assert_eq!(
+            lines,
+            vec![
+                "┏━━━━━━━━━┓",
+                "┃░░░░░░░░░┃",
+                "┗━┓░░░░░┏━┛",
+                "  ┗━┓░┏━┛  ",
+                "    ┃░┃",
+                "  ┏━┛█┗━┓  ",
+                "┏━┛█████┗━┓",
+                "┃█████████┃",
+                "┗━━━━━━━━━┛",
+            ]
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{
+    renderer::{big_clock_renderer::format_clock, build_bar, build_column, StyledRenderer},
+    Progress, Style, Theme,
+};
+use anyhow::Result;
+use crossterm::{
+    queue,
+    style::{Color, Print, ResetColor, Stylize},
+    terminal::{Clear, ClearType},
+};
+use std::io::Write;
+
+// Pie glyphs of the ring style, from empty to full
+const PIES: [char; 5] = ['○', '◔', '◑', '◕', '●'];
+const HOURGLASS: char = '⧗';
+const SYNTHWAVE_LEAD: char = '▶';
+
+/// One line redrawn in place at the cursor, with a compact variant of each
+/// style.
+pub struct InlineRenderer {
+    title: Option<String>,
+    progress: Progress,
+    theme: Theme,
+    style: Style,
+}
+
+impl StyledRenderer for InlineRenderer {
+    fn new(title: Option<String>, progress: Progress, theme: Theme) -> Self {
+        InlineRenderer {
+            title,
+            progress,
+            theme,
+            style: Style::Default,
+        }
+    }
+
+    // Returns to the start of the current line instead of moving to a row,
+    // so that the scrollback above is left alone
+    fn render<W: Write>(&self, w: &mut W) -> Result<u16> {
+        queue!(w, Print('\r'), Clear(ClearType::CurrentLine))?;
+        let row = self.render_content(w)?;
+        queue!(w, ResetColor)?;
+        Ok(row)
+    }
+
+    fn render_content<W: Write>(&self, w: &mut W) -> Result<u16> {
+        // Leave the last column free so the terminal never wraps the line
+        let width = Self::terminal_width().saturating_sub(1);
+        for (content, color) in self.build_segments(width) {
+            queue!(w, Print(content.with(color)))?;
+        }
+        Ok(0)
+    }
+}
+
+impl InlineRenderer {
+    /// Draws the compact variant of this style.
+    #[must_use]
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    // Title, style marker, bar and figures, the bar taking the width left
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn build_segments(&self, width: usize) -> Vec<(String, Color)> {
+        let palette = self.theme.palette;
+        let progress = &self.progress;
+        let ratio = progress.precise_ratio();
+        let percent = format!("{:.0}%", progress.ratio * 100.0);
+        let remaining = if progress.is_complete() {
+            "Completed".to_string()
+        } else {
+            format!("{} left", progress.format_remaining())
+        };
+
+        let mut lead = None;
+        let mut figures = format!("{percent} {remaining}");
+        let mut brackets = ("", "");
+        let mut bar = true;
+        match self.style {
+            Style::Hourglass => lead = Some(HOURGLASS.to_string()),
+            Style::Synthwave => lead = Some(SYNTHWAVE_LEAD.to_string()),
+            Style::Retro => {
+                brackets = ("[", "]");
+                figures = figures.to_uppercase();
+            }
+            Style::BigClock if !progress.is_complete() => {
+                figures = format!("{} {percent}", format_clock(progress.remaining));
+            }
+            Style::Ring => {
+                let pie = (ratio * (PIES.len() - 1) as f64).round() as usize;
+                lead = Some(PIES[pie.min(PIES.len() - 1)].to_string());
+            }
+            Style::Vertical => {
+                lead = Some(
+                    build_column(1, ratio, &self.theme.glyphs)
+                        .into_iter()
+                        .collect(),
+                );
+                bar = false;
+            }
+            Style::Calendar if progress.timespan.duration.num_days() >= 2 => {
+                let days = progress.timespan.duration.num_days();
+                let day = (progress.elapsed.num_days() + 1).min(days);
+                figures = format!("day {day}/{days} {percent}");
+            }
+            _ => {}
+        }
+
+        // The title gives way to the figures so that the line never wraps
+        let lead = lead.map(|lead| format!("{lead} "));
+        let lead_width = lead.as_ref().map_or(0, |lead| lead.chars().count());
+        let figures: String = figures
+            .chars()
+            .take(width.saturating_sub(lead_width))
+            .collect();
+        let mut used = lead_width
+            + figures.chars().count()
+            + if bar {
+                brackets.0.len() + brackets.1.len() + 1
+            } else {
+                0
+            };
+        let mut segments = Vec::new();
+        if let Some(title) = &self.title {
+            let room = width.saturating_sub(used);
+            // Keep a space between the title and what follows
+            let title = if title.chars().count() < room {
+                title.clone()
+            } else if room >= 2 {
+                let title: String = title.chars().take(room - 2).collect();
+                format!("{}…", title.trim_end())
+            } else {
+                String::new()
+            };
+            if !title.is_empty() {
+                let title = format!("{title} ");
+                used += title.chars().count();
+                segments.push((title, palette.text));
+            }
+        }
+        if let Some(lead) = lead {
+            segments.push((lead, palette.accent));
+        }
+        let bar_width = width.saturating_sub(used);
+        if bar && bar_width > 0 {
+            let bar = build_bar(bar_width, ratio, &self.theme.glyphs);
+            segments.push((brackets.0.to_string(), palette.border));
+            segments.push((bar, self.theme.bar_color()));
+            segments.push((format!("{} ", brackets.1), palette.border));
+        }
+        segments.push((figures, self.theme.remaining_color()));
+        segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Timespan;
+    use chrono::NaiveDateTime;
+
+    fn line(style: Style, title: Option<&str>, from: &str, to: &str, now: &str) -> String {
+        let time = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+        let timespan = Timespan::new(time(from), time(to)).unwrap();
+        InlineRenderer::new(
+            title.map(str::to_string),
+            timespan.progress(time(now)),
+            Theme::for_style(style),
+        )
+        .with_style(style)
+        .build_segments(30)
+        .into_iter()
+        .map(|(content, _)| content)
+        .collect()
+    }
+
+    #[test]
+    fn test_build_segments() {
+        let (from, to, now) = ("2025-09-03 09:00", "2025-09-03 13:00", "2025-09-03 10:00");
+        let test_cases = [
+            (Style::Default, None, "████▌░░░░░░░░░░░░░ 25% 3h left"),
+            (
+                Style::Default,
+                Some("Work"),
+                "Work ███▎░░░░░░░░░ 25% 3h left",
+            ),
+            (Style::Ring, None, "◔ ████░░░░░░░░░░░░ 25% 3h left"),
+            (Style::Vertical, None, "▂ 25% 3h left"),
+            (Style::BigClock, None, "████▌░░░░░░░░░░░░░ 3:00:00 25%"),
+        ];
+        for (style, title, expected) in test_cases {
+            assert_eq!(line(style, title, from, to, now), expected, "{style:?}");
+        }
+        // A title wider than the line is cut short instead of wrapping
+        let long = line(
+            Style::Default,
+            Some("Quarterly planning with the whole team"),
+            from,
+            to,
+            now,
+        );
+        assert_eq!(long, "Quarterly planni… 25% 3h left");
+        assert!(long.chars().count() <= 30);
+        assert_eq!(
+            line(Style::Default, None, from, to, "2025-09-03 14:00"),
+            "███████████████ 100% Completed"
+        );
+        assert_eq!(
+            line(
+                Style::Calendar,
+                None,
+                "2025-09-01 00:00",
+                "2025-09-11 00:00",
+                "2025-09-03 12:00"
+            ),
+            "████▎░░░░░░░░░░░░ day 3/10 25%"
+        );
+    }
+}
@@ -1,12 +1,36 @@
+pub mod axis;
+pub mod bar;
+pub mod big_clock_renderer;
+pub mod braille;
+pub mod calendar_renderer;
+pub mod dashboard_renderer;
 pub mod default_renderer;
+pub mod gantt_renderer;
+pub mod graph_renderer;
 pub mod hourglass_renderer;
+pub mod inline_renderer;
 pub mod retro_renderer;
+pub mod ring_renderer;
 pub mod styled_renderer;
 pub mod synthwave_renderer;
+pub mod timers_renderer;
+pub mod vertical_renderer;
 
+pub use axis::{build_axis, Axis};
+pub use bar::{build_bar, build_column, BarMode};
+pub use big_clock_renderer::BigClockRenderer;
+pub use braille::BrailleCanvas;
+pub use calendar_renderer::CalendarRenderer;
+pub use dashboard_renderer::DashboardRenderer;
 pub use default_renderer::DefaultRenderer;
+pub use gantt_renderer::GanttRenderer;
+pub use graph_renderer::GraphRenderer;
 pub use hourglass_renderer::HourglassRenderer;
+pub use inline_renderer::InlineRenderer;
 pub use retro_renderer::RetroRenderer;
+pub use ring_renderer::RingRenderer;
 pub use styled_renderer::Style;
 pub use styled_renderer::StyledRenderer;
 pub use synthwave_renderer::SynthwaveRenderer;
+pub use timers_renderer::TimersRenderer;
+pub use vertical_renderer::VerticalRenderer;
@@ -1,44 +1,54 @@
-use crate::{renderer::StyledRenderer, Progress};
+use crate::{
+    renderer::{build_bar, StyledRenderer},
+    Progress, Theme,
+};
 use anyhow::Result;
 use std::io::Write;
 
 pub struct RetroRenderer {
     title: Option<String>,
     progress: Progress,
+    theme: Theme,
 }
 
 impl StyledRenderer for RetroRenderer {
-    fn new(title: Option<String>, progress: Progress) -> Self {
-        RetroRenderer { title, progress }
+    fn new(title: Option<String>, progress: Progress, theme: Theme) -> Self {
+        RetroRenderer {
+            title,
+            progress,
+            theme,
+        }
     }
 
     fn render_content<W: Write>(&self, w: &mut W) -> Result<u16> {
         let width = Self::terminal_width();
+        let palette = self.theme.palette;
+        Self::render_background(w, palette.background)?;
         let title = self.build_title();
         let row = if let Some(title) = title {
-            Self::render_content_line(w, &title, 0)?
+            Self::render_colored_line(w, &title, palette.accent, 0)?
         } else {
             0
         };
-        let divider = Self::buid_divider(width);
-        let row = Self::render_content_line(w, &divider, row)?;
+        let divider = self.buid_divider(width);
+        let row = Self::render_colored_line(w, &divider, palette.border, row)?;
         let from = self.buid_from();
-        let row = Self::render_content_line(w, &from, row)?;
+        let row = Self::render_colored_line(w, &from, palette.text, row)?;
         let to = self.buid_to();
-        let row = Self::render_content_line(w, &to, row)?;
+        let row = Self::render_colored_line(w, &to, palette.text, row)?;
         let elapsed = self.build_elapsed();
-        let row = Self::render_content_line(w, &elapsed, row)?;
+        let row = Self::render_colored_line(w, &elapsed, palette.text, row)?;
         let remaining = self.build_remaining();
-        let row = Self::render_content_line(w, &remaining, row)?;
+        let row = Self::render_colored_line(w, &remaining, self.theme.remaining_color(), row)?;
         let row = Self::render_empty_line(w, row)?;
-        let row = Self::render_content_line(w, "[PROGRESS]", row)?;
+        let row = Self::render_colored_line(w, "[PROGRESS]", palette.text, row)?;
         let bar = self.build_bar(width);
-        let row = Self::render_content_line(w, &bar, row)?;
-        let row = Self::render_content_line(w, &divider, row)?;
+        let row = Self::render_colored_line(w, &bar, self.theme.bar_color(), row)?;
+        let row = Self::render_colored_line(w, &divider, palette.border, row)?;
         let status = self.build_status();
-        let row = Self::render_content_line(w, &status, row)?;
-        let row = Self::render_content_line(w, &divider, row)?;
-        let row = Self::render_content_line(w, "(Q) QUIT | (CTRL+C) ABORT", row)?;
+        let row = Self::render_colored_line(w, &status, palette.accent, row)?;
+        let row = Self::render_colored_line(w, &divider, palette.border, row)?;
+        let row = Self::render_colored_line(w, "(Q) QUIT | (CTRL+C) ABORT", palette.text, row)?;
         Ok(row)
     }
 }
@@ -59,8 +69,8 @@ impl RetroRenderer {
         }
     }
 
-    fn buid_divider(width: usize) -> String {
-        "=".repeat(width)
+    fn buid_divider(&self, width: usize) -> String {
+        self.theme.glyphs.horizontal.to_string().repeat(width)
     }
 
     fn buid_from(&self) -> String {
@@ -93,26 +103,12 @@ impl RetroRenderer {
         format!("[REMAINING] {}", self.progress.format_remaining())
     }
 
-    #[allow(clippy::cast_sign_loss)]
-    #[allow(clippy::cast_possible_truncation)]
-    #[allow(clippy::cast_precision_loss)]
     fn build_bar(&self, width: usize) -> String {
         let lhs = "[";
         let rhs = "]";
         let bar_width = width.saturating_sub(lhs.len() + rhs.len());
-        if self.progress.is_complete() {
-            format!("{}{}{}{}", lhs, "█".repeat(bar_width), "░".repeat(0), rhs)
-        } else {
-            let filled_length = (bar_width as f64 * self.progress.ratio).round() as usize;
-            let empty_length = bar_width - filled_length;
-            format!(
-                "{}{}{}{}",
-                lhs,
-                "█".repeat(filled_length),
-                "░".repeat(empty_length),
-                rhs
-            )
-        }
+        let bar = build_bar(bar_width, self.progress.precise_ratio(), &self.theme.glyphs);
+        format!("{lhs}{bar}{rhs}")
     }
 
     #[allow(clippy::cast_possible_truncation)]
@@ -134,7 +130,7 @@ impl RetroRenderer {
 mod tests {
     use chrono::DateTime;
 
-    use crate::{progress, Timespan};
+    use crate::{progress, Style, Timespan};
 
     use super::*;
 
@@ -167,7 +163,7 @@ mod tests {
                 timespan,
                 DateTime::from_timestamp(current, 0).unwrap().naive_utc(),
             );
-            let renderer = RetroRenderer::new(None, progress);
+            let renderer = RetroRenderer::new(None, progress, Theme::for_style(Style::Retro));
             let bar = renderer.build_bar(20);
             assert_eq!(bar, expected);
         }
@@ -0,0 +1,221 @@
+use crate::{
+    renderer::{
+        braille::{colored_cells, style_cells, BrailleCanvas, ColoredCell},
+        StyledRenderer,
+    },
+    Progress, Theme,
+};
+use anyhow::Result;
+use std::{f64::consts::PI, io::Write};
+
+// Inner radius of the donut as a share of the outer radius
+const INNER_RADIUS_RATIO: f64 = 0.6;
+// Rows used around the ring: title, information and two label rows
+const CHROME_ROWS: usize = 4;
+const CH_SPACE: char = ' ';
+
+pub struct RingRenderer {
+    title: Option<String>,
+    progress: Progress,
+    theme: Theme,
+}
+
+impl StyledRenderer for RingRenderer {
+    fn new(title: Option<String>, progress: Progress, theme: Theme) -> Self {
+        RingRenderer {
+            title,
+            progress,
+            theme,
+        }
+    }
+
+    fn render_content<W: Write>(&self, w: &mut W) -> Result<u16> {
+        let width = Self::terminal_width();
+        let height = Self::terminal_height();
+        let palette = self.theme.palette;
+        Self::render_background(w, palette.background)?;
+
+        let (columns, rows) = Self::ring_size(width, height);
+        let left_pad = CH_SPACE
+            .to_string()
+            .repeat(width.saturating_sub(columns) / 2);
+        let (ring, labels_inside) = self.build_rows(columns, rows);
+
+        let mut row = 0;
+        if let Some(title) = &self.title {
+            row = Self::render_colored_line(w, &Self::center(title, width), palette.text, row)?;
+        }
+        for cells in &ring {
+            let line = format!("{left_pad}{}", style_cells(cells));
+            row = Self::render_content_line(w, &line, row)?;
+        }
+        if !labels_inside {
+            for label in self.build_labels() {
+                let label = Self::center(&label, width);
+                row = Self::render_colored_line(w, &label, self.theme.remaining_color(), row)?;
+            }
+        }
+        let information = format!(
+            "{} → {}",
+            self.progress.timespan.format_from(),
+            self.progress.timespan.format_to()
+        );
+        row = Self::render_colored_line(w, &Self::center(&information, width), palette.text, row)?;
+        Ok(row)
+    }
+}
+
+impl RingRenderer {
+    /// Size of the ring in cells. A cell is about twice as tall as it is
+    /// wide, so a round ring needs twice as many columns as rows.
+    fn ring_size(width: usize, height: usize) -> (usize, usize) {
+        let rows = height.saturating_sub(CHROME_ROWS).min(width / 2);
+        (rows * 2, rows)
+    }
+
+    /// Dots of the elapsed arc, and the outline of the remaining arc.
+    #[allow(clippy::cast_precision_loss)]
+    fn build_ring(columns: usize, rows: usize, ratio: f64) -> (BrailleCanvas, BrailleCanvas) {
+        let mut filled = BrailleCanvas::new(columns, rows);
+        let mut track = BrailleCanvas::new(columns, rows);
+        let diameter = filled.dot_height().min(filled.dot_width()) as f64;
+        let outer = diameter / 2.0;
+        let inner = outer * INNER_RADIUS_RATIO;
+        for y in 0..filled.dot_height() {
+            for x in 0..filled.dot_width() {
+                let dx = x as f64 + 0.5 - outer;
+                let dy = y as f64 + 0.5 - outer;
+                let distance = dx.hypot(dy);
+                if distance < inner || distance > outer {
+                    continue;
+                }
+                // Clockwise from twelve o'clock, as a share of a full turn
+                let angle = dx.atan2(-dy).rem_euclid(2.0 * PI) / (2.0 * PI);
+                if angle < ratio {
+                    filled.set(x, y);
+                } else if distance > outer - 1.0 || distance < inner + 1.0 {
+                    track.set(x, y);
+                }
+            }
+        }
+        (filled, track)
+    }
+
+    fn build_labels(&self) -> [String; 2] {
+        let remaining = if self.progress.is_complete() {
+            "Completed".to_string()
+        } else {
+            format!("{} left", self.progress.format_remaining())
+        };
+        [remaining, format!("{:.0}%", self.progress.ratio * 100.0)]
+    }
+
+    /// Ring cells with their colours, and whether the labels fit in the hole.
+    fn build_rows(&self, columns: usize, rows: usize) -> (Vec<Vec<ColoredCell>>, bool) {
+        let (filled, track) = Self::build_ring(columns, rows, self.progress.precise_ratio());
+        let ring = filled.union(&track);
+        let border = self.theme.palette.border;
+        let mut lines = colored_cells(
+            &[(&filled, self.theme.bar_color()), (&track, border)],
+            border,
+        );
+
+        let labels = self.build_labels();
+        let first_row = rows.saturating_sub(1) / 2;
+        let fits = rows >= 2
+            && labels.iter().enumerate().all(|(i, label)| {
+                let length = label.chars().count() + 2;
+                let start = columns.saturating_sub(length) / 2;
+                length <= columns
+                    && (start..start + length).all(|c| !ring.is_cell_set(c, first_row + i))
+            });
+        if fits {
+            for (i, label) in labels.iter().enumerate() {
+                let start = columns.saturating_sub(label.chars().count()) / 2;
+                for (j, ch) in label.chars().enumerate() {
+                    lines[first_row + i][start + j] = (ch, self.theme.remaining_color());
+                }
+            }
+        }
+        (lines, fits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Style, Timespan};
+    use chrono::{Duration, NaiveDateTime};
+
+    fn renderer(elapsed: Duration) -> RingRenderer {
+        let fmt = "%Y-%m-%d %H:%M:%S";
+        let from = NaiveDateTime::parse_from_str("2025-09-01 09:00:00", fmt).unwrap();
+        let to = NaiveDateTime::parse_from_str("2025-09-01 10:00:00", fmt).unwrap();
+        let timespan = Timespan::new(from, to).unwrap();
+        RingRenderer::new(
+            None,
+            timespan.progress(from + elapsed),
+            Theme::for_style(Style::Ring),
+        )
+    }
+
+    #[test]
+    fn test_ring_size() {
+        let test_cases = [
+            ((80, 24), (40, 20)),
+            ((200, 50), (92, 46)),
+            ((30, 50), (30, 15)),
+            ((80, 4), (0, 0)),
+        ];
+        for ((width, height), expected) in test_cases {
+            assert_eq!(RingRenderer::ring_size(width, height), expected);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn test_build_ring() {
+        let (filled, track) = RingRenderer::build_ring(20, 10, 0.0);
+        assert_eq!(filled, BrailleCanvas::new(20, 10));
+        assert_ne!(track, BrailleCanvas::new(20, 10));
+
+        let (_, track) = RingRenderer::build_ring(20, 10, 1.0);
+        assert_eq!(track, BrailleCanvas::new(20, 10));
+
+        // A quarter of the ring is the top-right quadrant
+        let (filled, _) = RingRenderer::build_ring(20, 10, 0.25);
+        let center = 20.0;
+        for y in 0..40 {
+            for x in 0..40 {
+                if filled.is_set(x, y) {
+                    assert!(x as f64 + 0.5 >= center && y as f64 + 0.5 <= center);
+                }
+            }
+        }
+        assert!(filled.is_set(21, 1));
+        assert!(filled.is_set(38, 19));
+        assert!(!filled.is_set(38, 21));
+    }
+
+    #[test]
+    fn test_build_rows_with_labels_inside() {
+        let renderer = renderer(Duration::minutes(15));
+        let (lines, inside) = renderer.build_rows(40, 20);
+        assert!(inside);
+        let text: Vec<String> = lines
+            .iter()
+            .map(|cells| cells.iter().map(|(ch, _)| *ch).collect())
+            .collect();
+        assert!(text[9].contains("45m left"));
+        assert!(text[10].contains("25%"));
+    }
+
+    #[test]
+    fn test_build_rows_with_labels_outside() {
+        let renderer = renderer(Duration::minutes(15));
+        let (lines, inside) = renderer.build_rows(8, 4);
+        assert!(!inside);
+        assert_eq!(lines.len(), 4);
+        assert!(lines.iter().all(|cells| cells.len() == 8));
+    }
+}
@@ -1,9 +1,9 @@
-use crate::Progress;
+use crate::{Progress, Theme};
 use anyhow::Result;
 use crossterm::{
     cursor::MoveTo,
     queue,
-    style::{Color, PrintStyledContent, SetBackgroundColor, Stylize},
+    style::{Color, PrintStyledContent, ResetColor, SetBackgroundColor, Stylize},
     terminal::{size, Clear, ClearType},
 };
 use std::io::Write;
@@ -14,6 +14,12 @@ pub enum Style {
     Hourglass,
     Retro,
     Synthwave,
+    BigClock,
+    Ring,
+    Graph,
+    Calendar,
+    Vertical,
+    Gantt,
 }
 
 impl Style {
@@ -23,6 +29,12 @@ impl Style {
             "hourglass" => Style::Hourglass,
             "retro" => Style::Retro,
             "synthwave" => Style::Synthwave,
+            "bigclock" | "big-clock" => Style::BigClock,
+            "ring" => Style::Ring,
+            "graph" | "braille" => Style::Graph,
+            "calendar" => Style::Calendar,
+            "vertical" => Style::Vertical,
+            "gantt" | "timeline" => Style::Gantt,
             _ => Style::Default,
         }
     }
@@ -30,16 +42,17 @@ impl Style {
 
 pub trait StyledRenderer {
     #[allow(clippy::missing_errors_doc)]
-    fn new(title: Option<String>, progress: Progress) -> Self;
+    fn new(title: Option<String>, progress: Progress, theme: Theme) -> Self;
 
-    #[must_use]
+    #[must_use = "the returned row is where the cursor goes after rendering"]
     fn render<W: Write>(&self, w: &mut W) -> Result<u16> {
         let row = self.render_content(w);
-        queue!(w, Clear(ClearType::FromCursorDown))?;
+        // Reset before clearing so the background does not bleed below the content
+        queue!(w, ResetColor, Clear(ClearType::FromCursorDown))?;
         row
     }
 
-    #[must_use]
+    #[must_use = "the returned row is where the cursor goes after rendering"]
     #[allow(clippy::missing_errors_doc)]
     fn render_content<W: Write>(&self, w: &mut W) -> Result<u16>;
 
@@ -49,13 +62,36 @@ pub trait StyledRenderer {
         size().map_or(80, |(w, _)| w as usize)
     }
 
+    #[must_use]
+    fn terminal_height() -> usize {
+        size().map_or(24, |(_, h)| h as usize)
+    }
+
+    /// Centres `content` in `width` columns, cutting it when it is wider.
+    #[must_use]
+    fn center(content: &str, width: usize) -> String {
+        let content: String = content.chars().take(width).collect();
+        let padding = width.saturating_sub(content.chars().count()) / 2;
+        format!("{}{content}", " ".repeat(padding))
+    }
+
     #[allow(clippy::missing_errors_doc)]
     fn render_content_line<W: Write>(w: &mut W, content: &str, row: u16) -> Result<u16> {
+        Self::render_colored_line(w, content, Color::Reset, row)
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    fn render_colored_line<W: Write>(
+        w: &mut W,
+        content: &str,
+        color: Color,
+        row: u16,
+    ) -> Result<u16> {
         queue!(
             w,
             MoveTo(0, row),
             Clear(ClearType::CurrentLine),
-            PrintStyledContent(content.with(Color::Reset)),
+            PrintStyledContent(content.with(color)),
         )?;
         Ok(row + 1)
     }
@@ -1,72 +1,48 @@
 use anyhow::Result;
-use crossterm::style::{Color, Stylize};
+use crossterm::style::Stylize;
 use std::io::Write;
 use tracing::debug;
 
-use crate::{renderer::StyledRenderer, Progress};
-
-const SPACE: char = ' ';
-const VERTICAL_BORDER: char = '║';
-const HORIZONTAL_BORDER: char = '═';
-const BACKGROUND_COLOR: Color = Color::Rgb {
-    r: 59,
-    g: 50,
-    b: 85,
-};
-
-const TEXT_COLOR: Color = Color::Rgb {
-    r: 48,
-    g: 192,
-    b: 183,
-};
-
-const BORDER_COLOR: Color = Color::Rgb {
-    r: 73,
-    g: 128,
-    b: 153,
-};
-
-const BAR_COLOR: Color = Color::Rgb {
-    r: 238,
-    g: 34,
-    b: 125,
-};
-
-const ACCENT_COLOR: Color = Color::Rgb {
-    r: 253,
-    g: 128,
-    b: 131,
+use crate::{
+    renderer::{build_bar, StyledRenderer},
+    Progress, Theme,
 };
 
+const SPACE: char = ' ';
 pub struct SynthwaveRenderer {
     title: Option<String>,
     progress: Progress,
+    theme: Theme,
 }
 
 impl StyledRenderer for SynthwaveRenderer {
-    fn new(title: Option<String>, progress: Progress) -> Self {
-        SynthwaveRenderer { title, progress }
+    fn new(title: Option<String>, progress: Progress, theme: Theme) -> Self {
+        SynthwaveRenderer {
+            title,
+            progress,
+            theme,
+        }
     }
 
     fn render_content<W: Write>(&self, w: &mut W) -> Result<u16> {
         let width = Self::terminal_width();
-        Self::render_background(w, BACKGROUND_COLOR)?;
+        Self::render_background(w, self.theme.palette.background)?;
         let title = self.build_title();
         let row = if let Some(title) = title {
             Self::render_content_line(w, &title, 0)?
         } else {
             0
         };
-        let top_border = Self::build_top_border(width);
+        let top_border = self.build_top_border(width);
         let row = Self::render_content_line(w, &top_border, row)?;
         let bar = self.build_bar(width);
         let row = Self::render_content_line(w, &bar, row)?;
         let progress = self.build_progress(width);
         let row = Self::render_content_line(w, &progress, row)?;
-        let bottom_border = Self::build_bottom_border(width);
+        let bottom_border = self.build_bottom_border(width);
         let row = Self::render_content_line(w, &bottom_border, row)?;
         let message = self.build_message(width);
-        Self::render_background(w, BACKGROUND_COLOR)?;
+        Self::render_background(w, self.theme.palette.background)?;
         let row = Self::render_content_line(w, &message, row)?;
         Ok(row)
     }
@@ -74,18 +50,27 @@ impl StyledRenderer for SynthwaveRenderer {
 
 impl SynthwaveRenderer {
     fn build_title(&self) -> Option<String> {
+        let Theme {
+            palette, glyphs, ..
+        } = self.theme;
         if let Some(title) = &self.title {
             let content = format!(
                 "{}{}{}{}{}",
-                HORIZONTAL_BORDER.with(BORDER_COLOR).on(BACKGROUND_COLOR),
-                SPACE.on(BACKGROUND_COLOR),
+                glyphs
+                    .horizontal
+                    .with(palette.border)
+                    .on(palette.background),
+                SPACE.on(palette.background),
                 title
                     .to_uppercase()
                     .bold()
-                    .with(TEXT_COLOR)
-                    .on(BACKGROUND_COLOR),
-                SPACE.on(BACKGROUND_COLOR),
-                HORIZONTAL_BORDER.with(BORDER_COLOR).on(BACKGROUND_COLOR)
+                    .with(palette.text)
+                    .on(palette.background),
+                SPACE.on(palette.background),
+                glyphs
+                    .horizontal
+                    .with(palette.border)
+                    .on(palette.background)
             );
             Some(content)
         } else {
@@ -93,83 +78,89 @@ impl SynthwaveRenderer {
         }
     }
 
-    fn build_top_border(width: usize) -> String {
-        let lhs = '╔';
-        let rhs = '╗';
+    fn build_top_border(&self, width: usize) -> String {
+        let Theme {
+            palette, glyphs, ..
+        } = self.theme;
+        let lhs = glyphs.top_left;
+        let rhs = glyphs.top_right;
         format!(
             "{}{}{}",
-            lhs.to_string().with(BORDER_COLOR).on(BACKGROUND_COLOR),
-            HORIZONTAL_BORDER
+            lhs.to_string().with(palette.border).on(palette.background),
+            glyphs
+                .horizontal
                 .to_string()
                 .repeat(width.saturating_sub(lhs.len_utf16() + rhs.len_utf16()))
-                .with(BORDER_COLOR)
-                .on(BACKGROUND_COLOR),
-            rhs.to_string().with(BORDER_COLOR).on(BACKGROUND_COLOR),
+                .with(palette.border)
+                .on(palette.background),
+            rhs.to_string().with(palette.border).on(palette.background),
         )
     }
 
-    fn build_bottom_border(width: usize) -> String {
-        let lhs = '╚';
-        let rhs = '╝';
+    fn build_bottom_border(&self, width: usize) -> String {
+        let Theme {
+            palette, glyphs, ..
+        } = self.theme;
+        let lhs = glyphs.bottom_left;
+        let rhs = glyphs.bottom_right;
         format!(
             "{}{}{}",
-            lhs.to_string().with(BORDER_COLOR).on(BACKGROUND_COLOR),
-            HORIZONTAL_BORDER
+            lhs.to_string().with(palette.border).on(palette.background),
+            glyphs
+                .horizontal
                 .to_string()
                 .repeat(width.saturating_sub(lhs.len_utf16() + rhs.len_utf16()))
-                .with(BORDER_COLOR)
-                .on(BACKGROUND_COLOR),
-            rhs.to_string().with(BORDER_COLOR).on(BACKGROUND_COLOR),
+                .with(palette.border)
+                .on(palette.background),
+            rhs.to_string().with(palette.border).on(palette.background),
         )
     }
 
-    #[allow(clippy::cast_possible_truncation)]
-    #[allow(clippy::cast_sign_loss)]
-    #[allow(clippy::cast_precision_loss)]
     fn build_bar(&self, width: usize) -> String {
-        let bar_width = self.bar_width(width);
-        let filled_length = (bar_width as f64 * self.progress.ratio).round() as usize;
-        let empty_length = bar_width - filled_length;
+        let Theme {
+            palette, glyphs, ..
+        } = self.theme;
+        let bar = build_bar(
+            self.bar_width(width),
+            self.progress.precise_ratio(),
+            &glyphs,
+        );
         format!(
-            "{}{}{}{}{}{}{}{}{}{}{}{}",
-            VERTICAL_BORDER
+            "{}{}{}{}{}{}{}{}{}{}{}",
+            glyphs
+                .vertical
                 .to_string()
-                .with(BORDER_COLOR)
-                .on(BACKGROUND_COLOR),
-            SPACE.on(BACKGROUND_COLOR),
+                .with(palette.border)
+                .on(palette.background),
+            SPACE.on(palette.background),
             self.progress
                 .timespan
                 .format_from()
-                .with(TEXT_COLOR)
-                .on(BACKGROUND_COLOR),
-            SPACE.on(BACKGROUND_COLOR),
-            SPACE.on(BACKGROUND_COLOR),
-            "█"
-                .repeat(filled_length)
-                .with(BAR_COLOR)
-                .on(BACKGROUND_COLOR),
-            "░"
-                .repeat(empty_length)
-                .with(BAR_COLOR)
-                .on(BACKGROUND_COLOR),
-            SPACE.on(BACKGROUND_COLOR),
-            SPACE.on(BACKGROUND_COLOR),
+                .with(palette.text)
+                .on(palette.background),
+            SPACE.on(palette.background),
+            SPACE.on(palette.background),
+            bar.with(self.theme.bar_color()).on(palette.background),
+            SPACE.on(palette.background),
+            SPACE.on(palette.background),
             self.progress
                 .timespan
                 .format_to()
-                .with(TEXT_COLOR)
-                .on(BACKGROUND_COLOR),
-            SPACE.on(BACKGROUND_COLOR),
-            VERTICAL_BORDER
+                .with(palette.text)
+                .on(palette.background),
+            SPACE.on(palette.background),
+            glyphs
+                .vertical
                 .to_string()
-                .with(BORDER_COLOR)
-                .on(BACKGROUND_COLOR),
+                .with(palette.border)
+                .on(palette.background),
         )
     }
 
     fn bar_width(&self, width: usize) -> usize {
+        let glyphs = self.theme.glyphs;
         width
-            .saturating_sub(VERTICAL_BORDER.len_utf16())
+            .saturating_sub(glyphs.vertical.len_utf16())
             .saturating_sub(SPACE.len_utf16())
             .saturating_sub(self.progress.timespan.format_from().len())
             .saturating_sub(SPACE.len_utf16())
@@ -178,43 +169,55 @@ impl SynthwaveRenderer {
             .saturating_sub(SPACE.len_utf16())
             .saturating_sub(self.progress.timespan.format_to().len())
             .saturating_sub(SPACE.len_utf16())
-            .saturating_sub(VERTICAL_BORDER.len_utf16())
+            .saturating_sub(glyphs.vertical.len_utf16())
     }
 
     fn build_progress(&self, width: usize) -> String {
+        let Theme {
+            palette, glyphs, ..
+        } = self.theme;
         let left_space = SPACE.len_utf16()
             + self.progress.timespan.format_from().len()
             + SPACE.len_utf16()
             + SPACE.len_utf16();
-        let progress = format!(
-            "{:.0}% | {} elapsed | {} remaining",
+        let elapsed = format!(
+            "{:.0}% | {} elapsed | ",
             self.progress.ratio * 100.0,
             self.progress.format_elapsed(),
-            self.progress.format_remaining()
         );
+        let remaining = format!("{} remaining", self.progress.format_remaining());
         let rigtht_space = width
-            .saturating_sub(VERTICAL_BORDER.len_utf16())
+            .saturating_sub(glyphs.vertical.len_utf16())
             .saturating_sub(left_space)
-            .saturating_sub(progress.len())
-            .saturating_sub(VERTICAL_BORDER.len_utf16());
+            .saturating_sub(elapsed.len() + remaining.len())
+            .saturating_sub(glyphs.vertical.len_utf16());
         format!(
-            "{}{}{}{}{}",
-            VERTICAL_BORDER
+            "{}{}{}{}{}{}",
+            glyphs
+                .vertical
+                .to_string()
+                .with(palette.border)
+                .on(palette.background),
+            SPACE.to_string().repeat(left_space).on(palette.background),
+            elapsed.with(palette.text).on(palette.background),
+            remaining
+                .with(self.theme.remaining_color())
+                .on(palette.background),
+            SPACE
                 .to_string()
-                .with(BORDER_COLOR)
-                .on(BACKGROUND_COLOR),
-            SPACE.to_string().repeat(left_space).on(BACKGROUND_COLOR),
-            progress.with(TEXT_COLOR).on(BACKGROUND_COLOR),
-            SPACE.to_string().repeat(rigtht_space).on(BACKGROUND_COLOR),
-            VERTICAL_BORDER
+                .repeat(rigtht_space)
+                .on(palette.background),
+            glyphs
+                .vertical
                 .to_string()
-                .with(BORDER_COLOR)
-                .on(BACKGROUND_COLOR),
+                .with(palette.border)
+                .on(palette.background),
         )
     }
 
     #[allow(clippy::cast_possible_truncation)]
     fn build_message(&self, width: usize) -> String {
+        let palette = self.theme.palette;
         let (symbol_left, message, symbol_right) = match (self.progress.ratio * 100.0) as i32 {
             0..=10 => ('⚡', "SYSTEM INITIALIZING... NEON DREAMS LOADING", '⚡'),
             11..=25 => ('🌊', "RIDING THE DIGITAL WAVE... STAY CONNECTED", '🌊'),
@@ -245,11 +248,11 @@ impl SynthwaveRenderer {
         format!(
             "{}{}{}{}{}{}",
             SPACE.to_string().repeat(left_padding),
-            symbol_left.with(ACCENT_COLOR),
+            symbol_left.with(palette.accent),
             SPACE,
-            message.with(ACCENT_COLOR),
+            message.with(palette.accent),
             SPACE,
-            symbol_right.with(ACCENT_COLOR),
+            symbol_right.with(palette.accent),
         )
     }
 }
@@ -0,0 +1,211 @@
+use crate::{
+    renderer::{build_bar, StyledRenderer},
+    timer::Timer,
+    Progress, Theme, Thresholds,
+};
+use anyhow::Result;
+use crossterm::style::{Color, Stylize};
+use std::io::Write;
+
+// Narrowest tile before the timers stop being placed side by side
+const MIN_TILE_WIDTH: usize = 32;
+// Columns between tiles
+const TILE_GAP: usize = 2;
+// Rows per tile: header and bar, plus a blank row before the next tile
+const ROWS_PER_TILE: usize = 3;
+
+pub struct TimersRenderer {
+    title: Option<String>,
+    progress: Progress,
+    theme: Theme,
+    timers: Vec<Timer>,
+    thresholds: Option<Thresholds>,
+}
+
+impl StyledRenderer for TimersRenderer {
+    fn new(title: Option<String>, progress: Progress, theme: Theme) -> Self {
+        TimersRenderer {
+            title,
+            progress,
+            theme,
+            timers: Vec::new(),
+            thresholds: None,
+        }
+    }
+
+    fn render_content<W: Write>(&self, w: &mut W) -> Result<u16> {
+        let width = Self::terminal_width();
+        let mut height = Self::terminal_height();
+        let palette = self.theme.palette;
+        Self::render_background(w, palette.background)?;
+
+        let mut row = 0;
+        if let Some(title) = &self.title {
+            row = Self::render_colored_line(w, title, palette.text, row)?;
+            row = Self::render_empty_line(w, row)?;
+            height = height.saturating_sub(2);
+        }
+        let columns = Self::fit_columns(self.timers.len(), width, height);
+        for line in self.build_lines(width, columns) {
+            row = Self::render_content_line(w, &line, row)?;
+        }
+        Ok(row)
+    }
+}
+
+impl TimersRenderer {
+    /// Draws these timers, in the given order.
+    #[must_use]
+    pub fn with_timers(mut self, timers: Vec<Timer>) -> Self {
+        self.timers = timers;
+        self
+    }
+
+    /// Colours each timer's bar by its own remaining time.
+    #[must_use]
+    pub fn with_thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = Some(thresholds);
+        self
+    }
+
+    // Stacks the timers when they fit the height, otherwise tiles them in
+    // as few columns as needed
+    fn fit_columns(count: usize, width: usize, height: usize) -> usize {
+        let max_columns = (width / MIN_TILE_WIDTH).max(1);
+        (1..=max_columns)
+            .find(|columns| (count.div_ceil(*columns) * ROWS_PER_TILE).saturating_sub(1) <= height)
+            .unwrap_or(max_columns)
+    }
+
+    fn build_lines(&self, width: usize, columns: usize) -> Vec<String> {
+        let columns = columns.clamp(1, self.timers.len().max(1));
+        let tile_width = width.saturating_sub(TILE_GAP * (columns - 1)) / columns;
+        let gap = " ".repeat(TILE_GAP);
+        let mut lines = Vec::new();
+        for (i, tiles) in self.timers.chunks(columns).enumerate() {
+            if i > 0 {
+                lines.push(String::new());
+            }
+            let tiles: Vec<_> = tiles
+                .iter()
+                .map(|timer| self.build_tile(timer, tile_width))
+                .collect();
+            for line in 0..ROWS_PER_TILE - 1 {
+                let cells: Vec<_> = tiles
+                    .iter()
+                    .map(|tile| {
+                        let (content, color) = &tile[line];
+                        content.as_str().with(*color).to_string()
+                    })
+                    .collect();
+                lines.push(cells.join(&gap));
+            }
+        }
+        lines
+    }
+
+    // Header and bar of one timer, both exactly `width` cells wide
+    fn build_tile(&self, timer: &Timer, width: usize) -> [(String, Color); 2] {
+        let progress = timer.timespan.progress(self.progress.current_time);
+        let theme = self.thresholds.map_or(self.theme, |t| {
+            self.theme.with_urgency(t.urgency(&progress))
+        });
+        let header = Self::build_header(&timer.name, &progress, width);
+        let bar = build_bar(width, progress.precise_ratio(), &theme.glyphs);
+        [(header, self.theme.palette.text), (bar, theme.bar_color())]
+    }
+
+    // Name on the left, deadline and remaining time on the right
+    fn build_header(name: &str, progress: &Progress, width: usize) -> String {
+        let status = if progress.is_complete() {
+            format!("{}  Completed", progress.timespan.format_to())
+        } else {
+            format!(
+                "{}  {:.0}%  {} left",
+                progress.timespan.format_to(),
+                progress.ratio * 100.0,
+                progress.format_remaining()
+            )
+        };
+        let status: String = status.chars().take(width).collect();
+        let room = width - status.chars().count();
+        // Keep at least one space between the name and the status
+        let name = if name.chars().count() < room {
+            name.to_string()
+        } else if room >= 2 {
+            let name: String = name.chars().take(room - 2).collect();
+            format!("{}…", name.trim_end())
+        } else {
+            String::new()
+        };
+        format!("{name:<room$}{status}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Style, Timespan};
+    use chrono::NaiveDateTime;
+
+    fn time(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn renderer() -> TimersRenderer {
+        let now = time("2025-09-03 10:00");
+        let timer = |name, from, to| Timer::new(name, Timespan::new(time(from), time(to)).unwrap());
+        let timespan = Timespan::new(now, time("2025-09-04 10:00")).unwrap();
+        TimersRenderer::new(
+            None,
+            timespan.progress(now),
+            Theme::for_style(Style::Default),
+        )
+        .with_timers(vec![
+            timer("Standup", "2025-09-03 09:00", "2025-09-03 10:00"),
+            timer("Deploy window", "2025-09-03 09:00", "2025-09-03 13:00"),
+        ])
+    }
+
+    #[test]
+    fn test_fit_columns() {
+        assert_eq!(TimersRenderer::fit_columns(3, 80, 24), 1);
+        assert_eq!(TimersRenderer::fit_columns(3, 80, 8), 1);
+        assert_eq!(TimersRenderer::fit_columns(3, 80, 7), 2);
+        assert_eq!(TimersRenderer::fit_columns(6, 100, 5), 3);
+        assert_eq!(TimersRenderer::fit_columns(6, 40, 5), 1);
+    }
+
+    #[test]
+    fn test_build_header() {
+        let renderer = renderer();
+        let progress = renderer.timers[1]
+            .timespan
+            .progress(renderer.progress.current_time);
+        assert_eq!(
+            TimersRenderer::build_header("Deploy window", &progress, 36),
+            "Deploy window    13:00  25%  3h left"
+        );
+        assert_eq!(
+            TimersRenderer::build_header("Deploy window", &progress, 28),
+            "Deploy…  13:00  25%  3h left"
+        );
+        let progress = renderer.timers[0]
+            .timespan
+            .progress(renderer.progress.current_time);
+        assert_eq!(
+            TimersRenderer::build_header("Standup", &progress, 24),
+            "Standup 10:00  Completed"
+        );
+    }
+
+    #[test]
+    fn test_build_lines() {
+        let renderer = renderer();
+        let lines = renderer.build_lines(80, 1);
+        assert_eq!(lines.len(), 5);
+        assert!(lines[2].is_empty());
+        let lines = renderer.build_lines(80, 2);
+        assert_eq!(lines.len(), 2);
+    }
+}
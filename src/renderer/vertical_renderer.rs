@@ -0,0 +1,130 @@
+use crate::{
+    renderer::{build_column, StyledRenderer},
+    Progress, Theme,
+};
+use anyhow::Result;
+use std::io::Write;
+
+// Widest bar drawn, so that wider panes keep a slim column
+const MAX_BAR_WIDTH: usize = 8;
+// Rows under the bar: percent and remaining time
+const LABEL_ROWS: usize = 2;
+const CH_SPACE: char = ' ';
+
+pub struct VerticalRenderer {
+    title: Option<String>,
+    progress: Progress,
+    theme: Theme,
+}
+
+impl StyledRenderer for VerticalRenderer {
+    fn new(title: Option<String>, progress: Progress, theme: Theme) -> Self {
+        VerticalRenderer {
+            title,
+            progress,
+            theme,
+        }
+    }
+
+    fn render_content<W: Write>(&self, w: &mut W) -> Result<u16> {
+        let width = Self::terminal_width();
+        let height = Self::terminal_height();
+        let palette = self.theme.palette;
+        Self::render_background(w, palette.background)?;
+
+        let mut row = 0;
+        let title_rows = usize::from(self.title.is_some());
+        if let Some(title) = &self.title {
+            let title = Self::center(title, width);
+            row = Self::render_colored_line(w, &title, palette.text, row)?;
+        }
+        let bar_height = height.saturating_sub(title_rows + LABEL_ROWS).max(1);
+        for line in self.build_bar_lines(width, bar_height) {
+            row = Self::render_colored_line(w, &line, self.theme.bar_color(), row)?;
+        }
+        let [percent, remaining] = self.build_labels();
+        row = Self::render_colored_line(w, &Self::center(&percent, width), palette.text, row)?;
+        let remaining = Self::center(&remaining, width);
+        row = Self::render_colored_line(w, &remaining, self.theme.remaining_color(), row)?;
+        Ok(row)
+    }
+}
+
+impl VerticalRenderer {
+    fn bar_width(width: usize) -> usize {
+        width.saturating_sub(2).clamp(1, MAX_BAR_WIDTH)
+    }
+
+    // Rows of the bar, top to bottom, centred in the pane
+    fn build_bar_lines(&self, width: usize, height: usize) -> Vec<String> {
+        let bar_width = Self::bar_width(width);
+        let pad = CH_SPACE
+            .to_string()
+            .repeat(width.saturating_sub(bar_width) / 2);
+        build_column(height, self.progress.precise_ratio(), &self.theme.glyphs)
+            .into_iter()
+            .map(|cell| format!("{pad}{}", cell.to_string().repeat(bar_width)))
+            .collect()
+    }
+
+    fn build_labels(&self) -> [String; 2] {
+        let percent = format!("{:.0}%", self.progress.ratio * 100.0);
+        let remaining = if self.progress.is_complete() {
+            "Done".to_string()
+        } else {
+            self.progress.format_remaining()
+        };
+        [percent, remaining]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Style, Timespan};
+    use chrono::{Duration, NaiveDateTime};
+
+    fn renderer(elapsed: Duration) -> VerticalRenderer {
+        let fmt = "%Y-%m-%d %H:%M:%S";
+        let from = NaiveDateTime::parse_from_str("2025-09-01 09:00:00", fmt).unwrap();
+        let to = NaiveDateTime::parse_from_str("2025-09-01 10:00:00", fmt).unwrap();
+        let timespan = Timespan::new(from, to).unwrap();
+        VerticalRenderer::new(
+            None,
+            timespan.progress(from + elapsed),
+            Theme::for_style(Style::Vertical),
+        )
+    }
+
+    #[test]
+    fn test_build_bar_lines() {
+        let lines = renderer(Duration::minutes(45)).build_bar_lines(6, 4);
+        assert_eq!(lines, vec![" ░░░░", " ████", " ████", " ████"]);
+        let lines = renderer(Duration::minutes(15)).build_bar_lines(40, 2);
+        assert_eq!(
+            lines,
+            vec![
+                format!("{}░░░░░░░░", " ".repeat(16)),
+                format!("{}▄▄▄▄▄▄▄▄", " ".repeat(16)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_labels() {
+        assert_eq!(
+            renderer(Duration::minutes(15)).build_labels(),
+            ["25%", "45m"]
+        );
+        assert_eq!(
+            renderer(Duration::minutes(90)).build_labels(),
+            ["100%", "Done"]
+        );
+    }
+
+    #[test]
+    fn test_center() {
+        assert_eq!(VerticalRenderer::center("45m", 7), "  45m");
+        assert_eq!(VerticalRenderer::center("Deep Work", 4), "Deep");
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{Progress, Threshold};
+
+/// A point in the timespan that triggers a notification or a cue.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Milestone {
+    Threshold(Threshold),
+    Complete,
+}
+
+/// Milestones that fire exactly once each, in the order they are reached.
+///
+/// Progress is compared against what has already fired rather than against
+/// the previous tick, so a refresh interval longer than the gap between two
+/// milestones fires both on the next tick instead of skipping one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schedule {
+    pending: Vec<Threshold>,
+    completed: bool,
+}
+
+impl Schedule {
+    /// Milestones already behind `progress` are dropped, so starting doit
+    /// late does not fire a burst of them at once.
+    #[must_use]
+    pub fn new(thresholds: &[Threshold], progress: &Progress) -> Self {
+        let mut pending: Vec<Threshold> = thresholds
+            .iter()
+            .copied()
+            .filter(|threshold| !threshold.is_reached(progress))
+            .collect();
+        // Furthest from the end first, the order in which they are reached
+        pending.sort_by_key(|threshold| std::cmp::Reverse(remaining_at(*threshold, progress)));
+        Schedule {
+            pending,
+            completed: progress.is_complete(),
+        }
+    }
+
+    /// Milestones reached since the last call.
+    pub fn due(&mut self, progress: &Progress) -> Vec<Milestone> {
+        let mut due: Vec<Milestone> = Vec::new();
+        self.pending.retain(|threshold| {
+            let reached = threshold.is_reached(progress);
+            if reached {
+                due.push(Milestone::Threshold(*threshold));
+            }
+            !reached
+        });
+        if !self.completed && progress.is_complete() {
+            self.completed = true;
+            due.push(Milestone::Complete);
+        }
+        due
+    }
+}
+
+// Remaining time at which a threshold is reached, in milliseconds
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_precision_loss)]
+fn remaining_at(threshold: Threshold, progress: &Progress) -> i64 {
+    match threshold {
+        Threshold::Remaining(duration) => duration.num_milliseconds(),
+        Threshold::Percent(percent) => {
+            (progress.timespan.duration.num_milliseconds() as f64 * percent / 100.0) as i64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Timespan;
+    use chrono::{Duration, NaiveDateTime};
+
+    fn progress(now: &str) -> Progress {
+        let time = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+        Timespan::new(time("2025-09-03 09:00"), time("2025-09-03 10:00"))
+            .unwrap()
+            .progress(time(now))
+    }
+
+    #[test]
+    fn test_due() {
+        let ten = Threshold::Remaining(Duration::minutes(10));
+        let half = Threshold::Percent(50.0);
+        let mut schedule = Schedule::new(&[ten, half], &progress("2025-09-03 09:00"));
+        assert_eq!(schedule.due(&progress("2025-09-03 09:10")), vec![]);
+        assert_eq!(
+            schedule.due(&progress("2025-09-03 09:30")),
+            vec![Milestone::Threshold(half)]
+        );
+        assert_eq!(schedule.due(&progress("2025-09-03 09:31")), vec![]);
+        // One long interval skips past the last threshold and the end
+        assert_eq!(
+            schedule.due(&progress("2025-09-03 10:05")),
+            vec![Milestone::Threshold(ten), Milestone::Complete]
+        );
+        assert_eq!(schedule.due(&progress("2025-09-03 10:06")), vec![]);
+    }
+
+    #[test]
+    fn test_new_drops_reached() {
+        let ten = Threshold::Remaining(Duration::minutes(10));
+        let mut schedule = Schedule::new(&[ten], &progress("2025-09-03 09:55"));
+        assert_eq!(
+            schedule.due(&progress("2025-09-03 10:00")),
+            vec![Milestone::Complete]
+        );
+        let mut schedule = Schedule::new(&[ten], &progress("2025-09-03 10:30"));
+        assert_eq!(schedule.due(&progress("2025-09-03 10:31")), vec![]);
+    }
+}
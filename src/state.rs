@@ -0,0 +1,172 @@
+use crate::Timespan;
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use std::{
+    env, fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    process,
+};
+
+const STATE_ENV: &str = "DOIT_STATE";
+
+/// The timer of the running doit, saved so that status lines and prompts
+/// can show it without starting a TUI.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActiveTimer {
+    pub title: Option<String>,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    /// The doit that saved the timer, so that a killed one is not shown;
+    /// 0 in files saved before it was recorded
+    #[serde(default)]
+    pub pid: u32,
+}
+
+impl ActiveTimer {
+    #[must_use]
+    pub fn new(title: Option<String>, timespan: &Timespan) -> Self {
+        ActiveTimer {
+            title,
+            start: timespan.from,
+            end: timespan.to,
+            pid: process::id(),
+        }
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    pub fn timespan(&self) -> Result<Timespan> {
+        Timespan::new(self.start, self.end)
+    }
+
+    /// Replaces the saved timer, writing a temporary file first so that
+    /// readers never see half of it.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let temporary = path.with_extension("tmp");
+        fs::write(&temporary, serde_json::to_string(self)?)?;
+        fs::rename(&temporary, path)?;
+        Ok(())
+    }
+
+    /// The saved timer, or `None` when no doit is running.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        match fs::read_to_string(path) {
+            Ok(source) => Ok(Some(serde_json::from_str(&source)?)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// The saved timer while the doit that saved it is still running; the
+    /// file outlives a doit killed by SIGHUP, SIGTERM or a crash.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn load_running(path: &Path) -> Result<Option<Self>> {
+        Ok(Self::load(path)?.filter(|timer| timer.pid != 0 && is_running(timer.pid)))
+    }
+
+    /// Removes the saved timer unless another doit has replaced it since.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn clear(path: &Path) -> Result<()> {
+        // A file that cannot be read is not another doit's
+        if let Ok(Some(timer)) = Self::load(path) {
+            if timer.pid != process::id() {
+                return Ok(());
+            }
+        }
+        match fs::remove_file(path) {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+// Signal 0 only checks that the process exists; EPERM means it exists but
+// belongs to another user
+#[cfg(unix)]
+#[allow(clippy::cast_possible_wrap)]
+fn is_running(pid: u32) -> bool {
+    // SAFETY: kill with signal 0 sends nothing and has no side effects
+    let result = unsafe { libc::kill(pid as libc::pid_t, 0) };
+    result == 0 || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+// Without a cheap check, trust the file as before
+#[cfg(not(unix))]
+fn is_running(_pid: u32) -> bool {
+    true
+}
+
+/// State file: `$DOIT_STATE`, or `doit/timer.json` under the platform state
+/// directory (the cache directory where there is none).
+#[must_use]
+pub fn state_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os(STATE_ENV) {
+        return Some(PathBuf::from(path));
+    }
+    dirs::state_dir()
+        .or_else(dirs::cache_dir)
+        .map(|dir| dir.join("doit").join("timer.json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("doit").join("timer.json");
+        assert_eq!(ActiveTimer::load(&path).unwrap(), None);
+
+        let fmt = "%Y-%m-%d %H:%M";
+        let timespan = Timespan::new(
+            NaiveDateTime::parse_from_str("2025-09-03 09:00", fmt).unwrap(),
+            NaiveDateTime::parse_from_str("2025-09-03 17:00", fmt).unwrap(),
+        )
+        .unwrap();
+        let timer = ActiveTimer::new(Some("Work".to_string()), &timespan);
+        timer.save(&path).unwrap();
+        let loaded = ActiveTimer::load(&path).unwrap().unwrap();
+        assert_eq!(loaded, timer);
+        assert_eq!(loaded.timespan().unwrap(), timespan);
+
+        assert_eq!(ActiveTimer::load_running(&path).unwrap(), Some(timer));
+
+        ActiveTimer::clear(&path).unwrap();
+        ActiveTimer::clear(&path).unwrap();
+        assert_eq!(ActiveTimer::load(&path).unwrap(), None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_other_process() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("timer.json");
+        let fmt = "%Y-%m-%d %H:%M";
+        let timespan = Timespan::new(
+            NaiveDateTime::parse_from_str("2025-09-03 09:00", fmt).unwrap(),
+            NaiveDateTime::parse_from_str("2025-09-03 17:00", fmt).unwrap(),
+        )
+        .unwrap();
+
+        // A process that has already exited
+        let mut child = std::process::Command::new("true").spawn().unwrap();
+        child.wait().unwrap();
+        let dead = ActiveTimer {
+            pid: child.id(),
+            ..ActiveTimer::new(None, &timespan)
+        };
+        dead.save(&path).unwrap();
+        assert_eq!(ActiveTimer::load_running(&path).unwrap(), None);
+
+        // Saved by another doit, so this one leaves it in place
+        ActiveTimer::clear(&path).unwrap();
+        assert_eq!(ActiveTimer::load(&path).unwrap(), Some(dead));
+    }
+}
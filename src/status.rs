@@ -0,0 +1,269 @@
+use crate::{statusbar, urgency::Urgency, Progress, Thresholds};
+use anyhow::Result;
+use crossterm::tty::IsTty;
+use serde::Serialize;
+use std::io::stdout;
+
+/// Version of the JSON status object, raised whenever a field changes
+/// meaning or is removed. New fields may appear without a new version.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// How `--once` prints the status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    /// A waybar custom module object
+    Waybar,
+    /// A single i3bar or swaybar block
+    I3bar,
+}
+
+impl Format {
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "waybar" => Some(Format::Waybar),
+            "i3bar" | "swaybar" => Some(Format::I3bar),
+            _ => None,
+        }
+    }
+
+    /// Whether the reader is a status bar, which treats the end of the
+    /// stream as a failed module.
+    #[must_use]
+    pub fn is_status_bar(self) -> bool {
+        matches!(self, Format::Waybar | Format::I3bar)
+    }
+}
+
+/// Where the refresh loop writes each tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Output {
+    /// Full-screen renderer in raw mode
+    Tui,
+    /// A plain status line whenever it changes, for logs and pipes
+    Text,
+    /// One JSON status per line, without touching the terminal
+    Ndjson,
+    /// One waybar module object per line
+    Waybar,
+    /// The i3bar protocol header, then an endless array of blocks
+    I3bar,
+}
+
+impl Output {
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "auto" => Some(Self::detect()),
+            "tui" => Some(Output::Tui),
+            "text" | "plain" => Some(Output::Text),
+            "ndjson" | "jsonl" => Some(Output::Ndjson),
+            "waybar" => Some(Output::Waybar),
+            "i3bar" | "swaybar" => Some(Output::I3bar),
+            _ => None,
+        }
+    }
+
+    /// The TUI on a terminal, plain text when stdout is a file or a pipe.
+    #[must_use]
+    pub fn detect() -> Self {
+        Self::detect_with(stdout().is_tty())
+    }
+
+    fn detect_with(is_tty: bool) -> Self {
+        if is_tty {
+            Output::Tui
+        } else {
+            Output::Text
+        }
+    }
+
+    /// Format of each streamed line, `None` for the TUI.
+    #[must_use]
+    pub fn format(self) -> Option<Format> {
+        match self {
+            Output::Tui => None,
+            Output::Text => Some(Format::Text),
+            Output::Ndjson => Some(Format::Json),
+            Output::Waybar => Some(Format::Waybar),
+            Output::I3bar => Some(Format::I3bar),
+        }
+    }
+}
+
+/// Where the current time is relative to the timespan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum State {
+    Pending,
+    Running,
+    Complete,
+}
+
+impl State {
+    #[must_use]
+    pub fn of(progress: &Progress) -> Self {
+        if progress.is_complete() {
+            State::Complete
+        } else if progress.current_time < progress.timespan.from {
+            State::Pending
+        } else {
+            State::Running
+        }
+    }
+}
+
+/// The same values formatted as the renderers show them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Formatted {
+    pub start: String,
+    pub end: String,
+    pub percent: String,
+    pub elapsed: String,
+    pub remaining: String,
+}
+
+/// A snapshot of the progress for scripts.
+#[derive(Debug, Clone, Serialize)]
+pub struct Status {
+    pub version: u32,
+    pub title: Option<String>,
+    pub state: State,
+    pub urgency: Urgency,
+    #[serde(flatten)]
+    pub progress: Progress,
+    pub formatted: Formatted,
+}
+
+impl Status {
+    #[must_use]
+    pub fn new(title: Option<String>, progress: Progress) -> Self {
+        let timespan = progress.timespan;
+        Status {
+            version: SCHEMA_VERSION,
+            title,
+            state: State::of(&progress),
+            urgency: Thresholds::default().urgency(&progress),
+            formatted: Formatted {
+                start: timespan.format_from(),
+                end: timespan.format_to(),
+                percent: format!("{:.0}%", progress.ratio * 100.0),
+                elapsed: progress.format_elapsed(),
+                remaining: progress.format_remaining(),
+            },
+            progress,
+        }
+    }
+
+    /// Recomputes the urgency against these thresholds instead of the
+    /// defaults.
+    #[must_use]
+    pub fn with_thresholds(mut self, thresholds: &Thresholds) -> Self {
+        self.urgency = thresholds.urgency(&self.progress);
+        self
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// One line such as `42% 1h 5m remaining`.
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        match self.state {
+            State::Complete => format!("{} Completed", self.formatted.percent),
+            _ => format!(
+                "{} {} remaining",
+                self.formatted.percent, self.formatted.remaining
+            ),
+        }
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    pub fn format(&self, format: Format) -> Result<String> {
+        match format {
+            Format::Text => Ok(self.to_text()),
+            Format::Json => self.to_json(),
+            Format::Waybar => Ok(serde_json::to_string(&statusbar::waybar(self))?),
+            Format::I3bar => Ok(serde_json::to_string(&[statusbar::i3bar_block(self)])?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Timespan;
+    use chrono::NaiveDateTime;
+
+    fn time(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn status(now: &str) -> Status {
+        let timespan =
+            Timespan::new(time("2025-09-03 09:00:00"), time("2025-09-03 17:00:00")).unwrap();
+        Status::new(Some("Work".to_string()), timespan.progress(time(now)))
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&status("2025-09-03 11:00:00").to_json().unwrap()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "version": 1,
+                "title": "Work",
+                "state": "running",
+                "urgency": "calm",
+                "start": "2025-09-03T09:00:00",
+                "end": "2025-09-03T17:00:00",
+                "duration": 28800,
+                "now": "2025-09-03T11:00:00",
+                "ratio": 0.25,
+                "elapsed": 7200,
+                "remaining": 21600,
+                "formatted": {
+                    "start": "09:00",
+                    "end": "17:00",
+                    "percent": "25%",
+                    "elapsed": "2h",
+                    "remaining": "6h",
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_output_detect_with() {
+        assert_eq!(Output::detect_with(true), Output::Tui);
+        assert_eq!(Output::detect_with(false), Output::Text);
+    }
+
+    #[test]
+    fn test_is_status_bar() {
+        assert!(Format::Waybar.is_status_bar());
+        assert!(Format::I3bar.is_status_bar());
+        assert!(!Format::Json.is_status_bar());
+        assert!(!Format::Text.is_status_bar());
+    }
+
+    #[test]
+    fn test_state() {
+        assert_eq!(status("2025-09-03 08:00:00").state, State::Pending);
+        assert_eq!(status("2025-09-03 09:00:00").state, State::Running);
+        assert_eq!(status("2025-09-03 17:00:00").state, State::Complete);
+    }
+
+    #[test]
+    fn test_to_text() {
+        assert_eq!(status("2025-09-03 11:00:00").to_text(), "25% 6h remaining");
+        assert_eq!(status("2025-09-03 18:00:00").to_text(), "100% Completed");
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    status::{State, Status},
+    urgency::Urgency,
+};
+use serde::Serialize;
+
+/// First line of the i3bar protocol, before the endless array of
+/// status lines.
+pub const I3BAR_HEADER: &str = r#"{"version":1}"#;
+const I3BAR_NAME: &str = "doit";
+const WARNING_COLOR: &str = "#e5c07b";
+const CRITICAL_COLOR: &str = "#e06c75";
+
+/// Output of a waybar custom module with `"return-type": "json"`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WaybarModule {
+    pub text: String,
+    pub tooltip: String,
+    /// `calm`, `warning`, `critical` or `complete`, for styling in CSS
+    pub class: &'static str,
+    pub percentage: u8,
+}
+
+/// One block of an i3bar or swaybar status line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct I3barBlock {
+    pub name: &'static str,
+    pub full_text: String,
+    pub short_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<&'static str>,
+    pub urgent: bool,
+}
+
+/// Class of the status, changing as the deadline nears.
+#[must_use]
+pub fn class(status: &Status) -> &'static str {
+    match (status.state, status.urgency) {
+        (State::Complete, _) => "complete",
+        (_, Urgency::Critical) => "critical",
+        (_, Urgency::Warning) => "warning",
+        (_, Urgency::Calm) => "calm",
+    }
+}
+
+// Short text such as `42% 1h 5m`, led by the title when there is one
+fn text(status: &Status) -> String {
+    let figures = match status.state {
+        State::Complete => "done".to_string(),
+        _ => format!(
+            "{} {}",
+            status.formatted.percent, status.formatted.remaining
+        ),
+    };
+    match &status.title {
+        Some(title) => format!("{title} {figures}"),
+        None => figures,
+    }
+}
+
+#[must_use]
+#[allow(clippy::cast_sign_loss)]
+#[allow(clippy::cast_possible_truncation)]
+pub fn waybar(status: &Status) -> WaybarModule {
+    let formatted = &status.formatted;
+    let mut tooltip = vec![
+        format!("{} → {}", formatted.start, formatted.end),
+        format!(
+            "{} elapsed, {} remaining",
+            formatted.elapsed, formatted.remaining
+        ),
+    ];
+    if let Some(title) = &status.title {
+        tooltip.insert(0, title.clone());
+    }
+    WaybarModule {
+        text: text(status),
+        tooltip: tooltip.join("\n"),
+        class: class(status),
+        percentage: (status.progress.ratio * 100.0).round() as u8,
+    }
+}
+
+#[must_use]
+pub fn i3bar_block(status: &Status) -> I3barBlock {
+    let short_text = match status.state {
+        State::Complete => "done".to_string(),
+        _ => status.formatted.remaining.clone(),
+    };
+    let color = match class(status) {
+        "critical" => Some(CRITICAL_COLOR),
+        "warning" => Some(WARNING_COLOR),
+        _ => None,
+    };
+    I3barBlock {
+        name: I3BAR_NAME,
+        full_text: text(status),
+        short_text,
+        color,
+        urgent: class(status) == "critical",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Thresholds, Timespan};
+    use chrono::NaiveDateTime;
+
+    fn status(now: &str) -> Status {
+        let time = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+        let timespan = Timespan::new(time("2025-09-03 09:00"), time("2025-09-03 10:00")).unwrap();
+        Status::new(Some("Focus".to_string()), timespan.progress(time(now)))
+            .with_thresholds(&Thresholds::default())
+    }
+
+    #[test]
+    fn test_waybar() {
+        assert_eq!(
+            waybar(&status("2025-09-03 09:15")),
+            WaybarModule {
+                text: "Focus 25% 45m".to_string(),
+                tooltip: "Focus\n09:00 → 10:00\n15m elapsed, 45m remaining".to_string(),
+                class: "calm",
+                percentage: 25,
+            }
+        );
+        assert_eq!(waybar(&status("2025-09-03 09:55")).class, "warning");
+        assert_eq!(waybar(&status("2025-09-03 09:59")).class, "critical");
+        let complete = waybar(&status("2025-09-03 10:00"));
+        assert_eq!(complete.class, "complete");
+        assert_eq!(complete.text, "Focus done");
+    }
+
+    #[test]
+    fn test_i3bar_block() {
+        let block = i3bar_block(&status("2025-09-03 09:59"));
+        assert_eq!(
+            serde_json::to_string(&block).unwrap(),
+            r##"{"name":"doit","full_text":"Focus 98% 1m","short_text":"1m","color":"#e06c75","urgent":true}"##
+        );
+        let block = i3bar_block(&status("2025-09-03 09:15"));
+        assert_eq!(block.color, None);
+        assert!(!block.urgent);
+    }
+}
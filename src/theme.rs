@@ -0,0 +1,450 @@
+use crate::{color::ColorDepth, error::DoItError, renderer::BarMode, urgency::Urgency, Style};
+use anyhow::{format_err, Result};
+use crossterm::style::Color;
+use serde::{Deserialize, Deserializer};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+// Palettes shipped with the binary, selectable by name with `--theme`
+const BUILTIN_THEMES: [(&str, &str); 4] = [
+    ("dracula", include_str!("../themes/dracula.toml")),
+    ("mono", include_str!("../themes/mono.toml")),
+    ("nord", include_str!("../themes/nord.toml")),
+    ("synthwave", include_str!("../themes/synthwave.toml")),
+];
+
+const THEMES_DIR_ENV: &str = "DOIT_THEMES_DIR";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    pub background: Color,
+    pub text: Color,
+    pub border: Color,
+    pub bar: Color,
+    pub accent: Color,
+    pub warning: Color,
+    pub critical: Color,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyphs {
+    pub filled: char,
+    pub empty: char,
+    pub horizontal: char,
+    pub vertical: char,
+    pub top_left: char,
+    pub top_right: char,
+    pub bottom_left: char,
+    pub bottom_right: char,
+    pub bar: BarMode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub palette: Palette,
+    pub glyphs: Glyphs,
+    pub urgency: Urgency,
+}
+
+/// Partial theme as written in a TOML file. Every field is optional and
+/// overrides the corresponding value of the style's own theme.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThemeFile {
+    pub palette: PaletteOverrides,
+    pub glyphs: GlyphOverrides,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PaletteOverrides {
+    #[serde(deserialize_with = "deserialize_color")]
+    pub background: Option<Color>,
+    #[serde(deserialize_with = "deserialize_color")]
+    pub text: Option<Color>,
+    #[serde(deserialize_with = "deserialize_color")]
+    pub border: Option<Color>,
+    #[serde(deserialize_with = "deserialize_color")]
+    pub bar: Option<Color>,
+    #[serde(deserialize_with = "deserialize_color")]
+    pub accent: Option<Color>,
+    #[serde(deserialize_with = "deserialize_color")]
+    pub warning: Option<Color>,
+    #[serde(deserialize_with = "deserialize_color")]
+    pub critical: Option<Color>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GlyphOverrides {
+    pub filled: Option<char>,
+    pub empty: Option<char>,
+    pub horizontal: Option<char>,
+    pub vertical: Option<char>,
+    pub top_left: Option<char>,
+    pub top_right: Option<char>,
+    pub bottom_left: Option<char>,
+    pub bottom_right: Option<char>,
+    pub bar: Option<BarMode>,
+}
+
+// Terminal colours, with yellow and red for the urgency levels
+const PLAIN: Palette = Palette {
+    background: Color::Reset,
+    text: Color::Reset,
+    border: Color::Reset,
+    bar: Color::Reset,
+    accent: Color::Reset,
+    warning: Color::Yellow,
+    critical: Color::Red,
+};
+
+const SYNTHWAVE: Palette = Palette {
+    background: Color::Rgb {
+        r: 59,
+        g: 50,
+        b: 85,
+    },
+    text: Color::Rgb {
+        r: 48,
+        g: 192,
+        b: 183,
+    },
+    border: Color::Rgb {
+        r: 73,
+        g: 128,
+        b: 153,
+    },
+    bar: Color::Rgb {
+        r: 238,
+        g: 34,
+        b: 125,
+    },
+    accent: Color::Rgb {
+        r: 253,
+        g: 128,
+        b: 131,
+    },
+    warning: Color::Rgb {
+        r: 255,
+        g: 184,
+        b: 0,
+    },
+    critical: Color::Rgb {
+        r: 255,
+        g: 42,
+        b: 42,
+    },
+};
+
+const LIGHT_BOX: Glyphs = Glyphs {
+    filled: '█',
+    empty: '░',
+    horizontal: '─',
+    vertical: '│',
+    top_left: '┌',
+    top_right: '┐',
+    bottom_left: '└',
+    bottom_right: '┘',
+    bar: BarMode::Smooth,
+};
+
+const HEAVY_BOX: Glyphs = Glyphs {
+    horizontal: '━',
+    vertical: '┃',
+    top_left: '┏',
+    top_right: '┓',
+    bottom_left: '┗',
+    bottom_right: '┛',
+    ..LIGHT_BOX
+};
+
+const DOUBLE_BOX: Glyphs = Glyphs {
+    horizontal: '═',
+    vertical: '║',
+    top_left: '╔',
+    top_right: '╗',
+    bottom_left: '╚',
+    bottom_right: '╝',
+    ..LIGHT_BOX
+};
+
+impl Theme {
+    #[must_use]
+    pub fn for_style(style: Style) -> Self {
+        let (palette, glyphs) = match style {
+            Style::Default
+            | Style::BigClock
+            | Style::Ring
+            | Style::Graph
+            | Style::Calendar
+            | Style::Vertical
+            | Style::Gantt => (PLAIN, LIGHT_BOX),
+            Style::Retro => (
+                PLAIN,
+                Glyphs {
+                    horizontal: '=',
+                    ..LIGHT_BOX
+                },
+            ),
+            Style::Hourglass => (PLAIN, HEAVY_BOX),
+            Style::Synthwave => (SYNTHWAVE, DOUBLE_BOX),
+        };
+        Theme {
+            palette,
+            glyphs,
+            urgency: Urgency::Calm,
+        }
+    }
+
+    #[must_use]
+    pub fn with_overrides(self, overrides: &ThemeFile) -> Self {
+        let palette = &overrides.palette;
+        let glyphs = &overrides.glyphs;
+        Theme {
+            palette: Palette {
+                background: palette.background.unwrap_or(self.palette.background),
+                text: palette.text.unwrap_or(self.palette.text),
+                border: palette.border.unwrap_or(self.palette.border),
+                bar: palette.bar.unwrap_or(self.palette.bar),
+                accent: palette.accent.unwrap_or(self.palette.accent),
+                warning: palette.warning.unwrap_or(self.palette.warning),
+                critical: palette.critical.unwrap_or(self.palette.critical),
+            },
+            glyphs: Glyphs {
+                filled: glyphs.filled.unwrap_or(self.glyphs.filled),
+                empty: glyphs.empty.unwrap_or(self.glyphs.empty),
+                horizontal: glyphs.horizontal.unwrap_or(self.glyphs.horizontal),
+                vertical: glyphs.vertical.unwrap_or(self.glyphs.vertical),
+                top_left: glyphs.top_left.unwrap_or(self.glyphs.top_left),
+                top_right: glyphs.top_right.unwrap_or(self.glyphs.top_right),
+                bottom_left: glyphs.bottom_left.unwrap_or(self.glyphs.bottom_left),
+                bottom_right: glyphs.bottom_right.unwrap_or(self.glyphs.bottom_right),
+                bar: glyphs.bar.unwrap_or(self.glyphs.bar),
+            },
+            urgency: self.urgency,
+        }
+    }
+
+    /// Maps the palette down to the colours the terminal can show.
+    #[must_use]
+    pub fn with_color_depth(self, depth: ColorDepth) -> Self {
+        Theme {
+            palette: self.palette.with_color_depth(depth),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_urgency(self, urgency: Urgency) -> Self {
+        Theme { urgency, ..self }
+    }
+
+    /// Colour of the bar, escalated to the warning or critical colour.
+    #[must_use]
+    pub fn bar_color(&self) -> Color {
+        match self.urgency {
+            Urgency::Calm => self.palette.bar,
+            Urgency::Warning => self.palette.warning,
+            Urgency::Critical => self.palette.critical,
+        }
+    }
+
+    /// Colour of the remaining-time text, escalated like the bar.
+    #[must_use]
+    pub fn remaining_color(&self) -> Color {
+        match self.urgency {
+            Urgency::Calm => self.palette.text,
+            Urgency::Warning => self.palette.warning,
+            Urgency::Critical => self.palette.critical,
+        }
+    }
+}
+
+impl Palette {
+    #[must_use]
+    pub fn with_color_depth(self, depth: ColorDepth) -> Self {
+        Palette {
+            background: depth.map(self.background),
+            text: depth.map(self.text),
+            border: depth.map(self.border),
+            bar: depth.map(self.bar),
+            accent: depth.map(self.accent),
+            warning: depth.map(self.warning),
+            critical: depth.map(self.critical),
+        }
+    }
+}
+
+impl ThemeFile {
+    #[allow(clippy::missing_errors_doc)]
+    pub fn parse(source: &str, origin: &str) -> Result<Self> {
+        toml::from_str(source).map_err(|e| {
+            format_err!(DoItError::InvalidTheme {
+                origin: origin.to_string(),
+                message: e.message().to_string(),
+            })
+        })
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    pub fn load(path: &Path) -> Result<Self> {
+        let source = fs::read_to_string(path)?;
+        Self::parse(&source, &path.display().to_string())
+    }
+
+    /// Resolves `--theme` as a file path, then as a name in the themes
+    /// directory, then as one of the built-in themes.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn find(name: &str) -> Result<Self> {
+        let path = Path::new(name);
+        if path.is_file() {
+            return Self::load(path);
+        }
+        if let Some(dir) = themes_dir() {
+            let path = dir.join(format!("{name}.toml"));
+            if path.is_file() {
+                return Self::load(&path);
+            }
+        }
+        match BUILTIN_THEMES.iter().find(|(n, _)| *n == name) {
+            Some((_, source)) => Self::parse(source, name),
+            None => Err(format_err!(DoItError::ThemeNotFound {
+                name: name.to_string(),
+            })),
+        }
+    }
+
+    #[must_use]
+    pub fn builtin_names() -> Vec<&'static str> {
+        BUILTIN_THEMES.iter().map(|(name, _)| *name).collect()
+    }
+}
+
+/// Directory searched for `<name>.toml` themes: `$DOIT_THEMES_DIR`, or
+/// `doit/themes` under the platform config directory.
+#[must_use]
+pub fn themes_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os(THEMES_DIR_ENV) {
+        return Some(PathBuf::from(dir));
+    }
+    dirs::config_dir().map(|dir| dir.join("doit").join("themes"))
+}
+
+/// Accepts `#rrggbb`, an ANSI palette index (`0`-`255`) or a crossterm
+/// colour name such as `dark_cyan` or `reset`.
+#[allow(clippy::missing_errors_doc)]
+#[allow(clippy::missing_panics_doc)]
+pub fn parse_color(s: &str) -> Result<Color, String> {
+    let s = s.trim();
+    if let Some(hex) = s.strip_prefix('#') {
+        if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+            return Ok(Color::Rgb {
+                r: channel(0),
+                g: channel(2),
+                b: channel(4),
+            });
+        }
+    } else if let Ok(value) = s.parse::<u8>() {
+        return Ok(Color::AnsiValue(value));
+    } else if let Ok(color) = Color::try_from(s.to_lowercase().as_str()) {
+        return Ok(color);
+    }
+    Err(format!("Invalid color: {s}"))
+}
+
+fn deserialize_color<'de, D>(deserializer: D) -> Result<Option<Color>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    parse_color(&s).map(Some).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_color() {
+        let test_cases = [
+            (
+                "#3b3255",
+                Color::Rgb {
+                    r: 59,
+                    g: 50,
+                    b: 85,
+                },
+            ),
+            (
+                "#FFFFFF",
+                Color::Rgb {
+                    r: 255,
+                    g: 255,
+                    b: 255,
+                },
+            ),
+            ("208", Color::AnsiValue(208)),
+            ("dark_cyan", Color::DarkCyan),
+            ("Reset", Color::Reset),
+        ];
+        for (input, expected) in test_cases {
+            assert_eq!(parse_color(input), Ok(expected), "input: {input}");
+        }
+        for input in ["#12345", "#gggggg", "256", "purple", ""] {
+            assert!(parse_color(input).is_err(), "input: {input}");
+        }
+    }
+
+    #[test]
+    fn test_parse_theme_file() {
+        let source = r##"
+            [palette]
+            bar = "#ff0000"
+
+            [glyphs]
+            filled = "#"
+            bar = "block"
+        "##;
+        let overrides = ThemeFile::parse(source, "test").unwrap();
+        let theme = Theme::for_style(Style::Default).with_overrides(&overrides);
+        assert_eq!(theme.palette.bar, Color::Rgb { r: 255, g: 0, b: 0 });
+        assert_eq!(theme.palette.text, Color::Reset);
+        assert_eq!(theme.glyphs.filled, '#');
+        assert_eq!(theme.glyphs.empty, '░');
+        assert_eq!(theme.glyphs.bar, BarMode::Block);
+    }
+
+    #[test]
+    fn test_parse_theme_file_with_failure() {
+        let test_cases = [
+            "[palette]\nbar = \"nope\"",
+            "[glyphs]\nfilled = \"ab\"",
+            "[glyphs]\nbar = \"round\"",
+            "[unknown]\nkey = 1",
+        ];
+        for source in test_cases {
+            assert!(ThemeFile::parse(source, "test").is_err(), "{source}");
+        }
+    }
+
+    #[test]
+    fn test_builtin_themes() {
+        for name in ThemeFile::builtin_names() {
+            assert!(ThemeFile::find(name).is_ok(), "theme: {name}");
+        }
+        assert!(ThemeFile::find("no-such-theme").is_err());
+    }
+
+    #[test]
+    fn test_synthwave_builtin_matches_style() {
+        let overrides = ThemeFile::find("synthwave").unwrap();
+        let theme = Theme::for_style(Style::Default).with_overrides(&overrides);
+        let synthwave = Theme::for_style(Style::Synthwave);
+        assert_eq!(theme.palette, synthwave.palette);
+        assert_eq!(theme.glyphs, synthwave.glyphs);
+    }
+}
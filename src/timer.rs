@@ -0,0 +1,76 @@
+use crate::Timespan;
+use chrono::NaiveDateTime;
+
+/// A named timespan drawn next to others on one screen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timer {
+    pub name: String,
+    pub timespan: Timespan,
+}
+
+impl Timer {
+    #[must_use]
+    pub fn new(name: &str, timespan: Timespan) -> Self {
+        Timer {
+            name: name.to_string(),
+            timespan,
+        }
+    }
+}
+
+/// Orders timers by deadline, keeping the given order for equal deadlines.
+pub fn sort_by_deadline(timers: &mut [Timer]) {
+    timers.sort_by_key(|timer| timer.timespan.to);
+}
+
+/// The first timer of `timers`, sorted by deadline, that has not expired at
+/// `now`, or the last one when all have.
+#[must_use]
+pub fn next_deadline(timers: &[Timer], now: NaiveDateTime) -> Option<&Timer> {
+    timers
+        .iter()
+        .find(|timer| !timer.timespan.has_expired(now))
+        .or(timers.last())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timer(name: &str, from: &str, to: &str) -> Timer {
+        let fmt = "%Y-%m-%d %H:%M";
+        let timespan = Timespan::new(
+            NaiveDateTime::parse_from_str(from, fmt).unwrap(),
+            NaiveDateTime::parse_from_str(to, fmt).unwrap(),
+        )
+        .unwrap();
+        Timer::new(name, timespan)
+    }
+
+    #[test]
+    fn test_sort_by_deadline() {
+        let mut timers = vec![
+            timer("Sprint end", "2025-09-01 09:00", "2025-09-12 18:00"),
+            timer("Standup", "2025-09-03 10:00", "2025-09-03 10:15"),
+            timer("Deploy window", "2025-09-03 09:00", "2025-09-03 12:00"),
+            timer("Lunch", "2025-09-03 11:00", "2025-09-03 12:00"),
+        ];
+        sort_by_deadline(&mut timers);
+        let names: Vec<_> = timers.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["Standup", "Deploy window", "Lunch", "Sprint end"]);
+    }
+
+    #[test]
+    fn test_next_deadline() {
+        let timers = [
+            timer("Standup", "2025-09-03 10:00", "2025-09-03 10:15"),
+            timer("Lunch", "2025-09-03 11:00", "2025-09-03 12:00"),
+        ];
+        let now = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+        let name = |now| next_deadline(&timers, now).map(|timer| timer.name.as_str());
+        assert_eq!(name(now("2025-09-03 09:00")), Some("Standup"));
+        assert_eq!(name(now("2025-09-03 10:15")), Some("Lunch"));
+        assert_eq!(name(now("2025-09-03 13:00")), Some("Lunch"));
+        assert_eq!(next_deadline(&[], now("2025-09-03 09:00")), None);
+    }
+}
@@ -1,14 +1,27 @@
 use crate::{error::DoItError, Progress};
 use anyhow::{format_err, Result};
 use chrono::{Duration, NaiveDateTime};
+use serde::{Serialize, Serializer};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub struct Timespan {
+    #[serde(rename = "start")]
     pub from: NaiveDateTime,
+    #[serde(rename = "end")]
     pub to: NaiveDateTime,
+    #[serde(serialize_with = "serialize_seconds")]
     pub duration: Duration,
 }
 
+/// Serializes a duration as whole seconds.
+#[allow(clippy::missing_errors_doc)]
+pub fn serialize_seconds<S: Serializer>(
+    duration: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_i64(duration.num_seconds())
+}
+
 impl Timespan {
     #[allow(clippy::missing_errors_doc)]
     pub fn new(from: NaiveDateTime, to: NaiveDateTime) -> Result<Self> {
@@ -37,7 +50,7 @@ impl Timespan {
 
     #[must_use]
     pub fn format_from_with_string(&self, string: &str) -> String {
-        self.from.format(string).to_string()
+        Self::format_time_with_string(self.from, string)
     }
 
     #[must_use]
@@ -47,7 +60,12 @@ impl Timespan {
 
     #[must_use]
     pub fn format_to_with_string(&self, string: &str) -> String {
-        self.to.format(string).to_string()
+        Self::format_time_with_string(self.to, string)
+    }
+
+    #[must_use]
+    pub fn format_time_with_string(time: NaiveDateTime, string: &str) -> String {
+        time.format(string).to_string()
     }
 
     #[must_use]
@@ -0,0 +1,74 @@
+use crate::{renderer::build_bar, urgency::Urgency, Progress, Style, Theme, Thresholds};
+
+/// Cells of the mini bar unless `--width` says otherwise.
+pub const DEFAULT_WIDTH: usize = 8;
+// Seconds between tmux status refreshes in the snippet
+const STATUS_INTERVAL: u32 = 5;
+
+/// A status-right segment such as `#[fg=green]███▌░░░░#[default] 23m`.
+#[must_use]
+pub fn status_line(progress: &Progress, width: usize, thresholds: &Thresholds) -> String {
+    let color = match thresholds.urgency(progress) {
+        Urgency::Calm => "green",
+        Urgency::Warning => "yellow",
+        Urgency::Critical => "red",
+    };
+    let glyphs = Theme::for_style(Style::Default).glyphs;
+    let bar = build_bar(width, progress.precise_ratio(), &glyphs);
+    let remaining = if progress.is_complete() {
+        "done".to_string()
+    } else {
+        progress.format_remaining()
+    };
+    format!("#[fg={color}]{bar}#[default] {remaining}")
+}
+
+/// Shell commands that put `program tmux` in front of tmux's default
+/// status-right and refresh it every few seconds.
+#[must_use]
+pub fn snippet(program: &str) -> String {
+    format!(
+        "tmux set -g status-interval {STATUS_INTERVAL}\n\
+         tmux set -g status-right '#({program} tmux) %H:%M %d-%b-%y'"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Timespan;
+    use chrono::NaiveDateTime;
+
+    fn progress(now: &str) -> Progress {
+        let time = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+        Timespan::new(time("2025-09-03 09:00"), time("2025-09-03 10:00"))
+            .unwrap()
+            .progress(time(now))
+    }
+
+    #[test]
+    fn test_status_line() {
+        let thresholds = Thresholds::default();
+        assert_eq!(
+            status_line(&progress("2025-09-03 09:15"), 8, &thresholds),
+            "#[fg=green]██░░░░░░#[default] 45m"
+        );
+        assert_eq!(
+            status_line(&progress("2025-09-03 09:55"), 4, &thresholds),
+            "#[fg=yellow]███▋#[default] 5m"
+        );
+        assert_eq!(
+            status_line(&progress("2025-09-03 10:30"), 4, &thresholds),
+            "#[fg=red]████#[default] done"
+        );
+    }
+
+    #[test]
+    fn test_snippet() {
+        assert_eq!(
+            snippet("/usr/local/bin/doit"),
+            "tmux set -g status-interval 5\n\
+             tmux set -g status-right '#(/usr/local/bin/doit tmux) %H:%M %d-%b-%y'"
+        );
+    }
+}
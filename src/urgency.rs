@@ -0,0 +1,120 @@
+use crate::Progress;
+use chrono::Duration;
+use serde::Serialize;
+
+/// How close a timespan is to its end, used to escalate colours.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Urgency {
+    #[default]
+    Calm,
+    Warning,
+    Critical,
+}
+
+/// A point before the end of a timespan, given as time or share remaining.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Threshold {
+    Remaining(Duration),
+    Percent(f64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Thresholds {
+    pub warning: Option<Threshold>,
+    pub critical: Option<Threshold>,
+}
+
+impl Threshold {
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn is_reached(&self, progress: &Progress) -> bool {
+        match *self {
+            Threshold::Remaining(duration) => progress.remaining <= duration,
+            Threshold::Percent(percent) => {
+                let remaining = progress.remaining.num_milliseconds() as f64
+                    / progress.timespan.duration.num_milliseconds() as f64;
+                remaining * 100.0 <= percent
+            }
+        }
+    }
+}
+
+impl Default for Thresholds {
+    /// Warning at 10 minutes left and critical at 2, as on the command line.
+    fn default() -> Self {
+        Thresholds {
+            warning: Some(Threshold::Remaining(Duration::minutes(10))),
+            critical: Some(Threshold::Remaining(Duration::minutes(2))),
+        }
+    }
+}
+
+impl Thresholds {
+    #[must_use]
+    pub fn urgency(&self, progress: &Progress) -> Urgency {
+        let reached =
+            |threshold: Option<Threshold>| threshold.is_some_and(|t| t.is_reached(progress));
+        if reached(self.critical) {
+            Urgency::Critical
+        } else if reached(self.warning) {
+            Urgency::Warning
+        } else {
+            Urgency::Calm
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Timespan;
+    use chrono::NaiveDateTime;
+
+    #[test]
+    fn test_urgency() {
+        let fmt = "%Y-%m-%d %H:%M:%S";
+        let from = NaiveDateTime::parse_from_str("2025-09-01 09:00:00", fmt).unwrap();
+        let to = NaiveDateTime::parse_from_str("2025-09-01 10:00:00", fmt).unwrap();
+        let timespan = Timespan::new(from, to).unwrap();
+        let by_time = Thresholds {
+            warning: Some(Threshold::Remaining(Duration::minutes(10))),
+            critical: Some(Threshold::Remaining(Duration::minutes(2))),
+        };
+        let by_percent = Thresholds {
+            warning: Some(Threshold::Percent(25.0)),
+            critical: Some(Threshold::Percent(5.0)),
+        };
+        let test_cases = [
+            (Duration::minutes(0), Urgency::Calm, Urgency::Calm),
+            (Duration::minutes(45), Urgency::Calm, Urgency::Warning),
+            (Duration::minutes(49), Urgency::Calm, Urgency::Warning),
+            (Duration::minutes(50), Urgency::Warning, Urgency::Warning),
+            (Duration::minutes(57), Urgency::Warning, Urgency::Critical),
+            (Duration::minutes(58), Urgency::Critical, Urgency::Critical),
+            (Duration::minutes(90), Urgency::Critical, Urgency::Critical),
+        ];
+        for (offset, expected_by_time, expected_by_percent) in test_cases {
+            let progress = timespan.progress(from + offset);
+            assert_eq!(by_time.urgency(&progress), expected_by_time, "{offset}");
+            assert_eq!(
+                by_percent.urgency(&progress),
+                expected_by_percent,
+                "{offset}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_urgency_without_thresholds() {
+        let fmt = "%Y-%m-%d %H:%M:%S";
+        let from = NaiveDateTime::parse_from_str("2025-09-01 09:00:00", fmt).unwrap();
+        let to = NaiveDateTime::parse_from_str("2025-09-01 10:00:00", fmt).unwrap();
+        let timespan = Timespan::new(from, to).unwrap();
+        let thresholds = Thresholds {
+            warning: None,
+            critical: None,
+        };
+        assert_eq!(thresholds.urgency(&timespan.progress(to)), Urgency::Calm);
+    }
+}